@@ -0,0 +1,43 @@
+use crate::Day01;
+use aoc_common::generate::{Generated, Generator};
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+
+impl Generator for Day01 {
+    const SIZE: usize = 1000;
+
+    /// Two lists of `size` location IDs, the right list reuses some IDs of
+    /// the left list so that the similarity score isn't zero.
+    fn generate(rng: &mut StdRng, size: usize) -> Generated {
+        let left: Vec<u32> = (0..size).map(|_| rng.gen_range(10000..100000)).collect();
+        let right: Vec<u32> = (0..size)
+            .map(|_| match rng.gen_bool(0.2) {
+                true => *left.choose(rng).unwrap(),
+                false => rng.gen_range(10000..100000),
+            })
+            .collect();
+
+        let mut sorted_left = left.clone();
+        let mut sorted_right = right.clone();
+        sorted_left.sort_unstable();
+        sorted_right.sort_unstable();
+        let distance: u32 = sorted_left
+            .iter()
+            .zip(&sorted_right)
+            .map(|(l, r)| l.abs_diff(*r))
+            .sum();
+        let similarity: u32 = left
+            .iter()
+            .map(|l| l * right.iter().filter(|r| *r == l).count() as u32)
+            .sum();
+
+        let lines: Vec<String> = left
+            .iter()
+            .zip(&right)
+            .map(|(l, r)| format!("{l}   {r}"))
+            .collect();
+
+        Generated::new(lines.join("\n"))
+            .with_part1(distance)
+            .with_part2(similarity)
+    }
+}
@@ -0,0 +1,116 @@
+pub mod generate;
+
+use anyhow::{Context, Result};
+use aoc_common::Solution;
+use std::fmt::Display;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = (Vec<u32>, Vec<u32>);
+
+    fn parse(data: &str) -> Result<Self::Input> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display> {
+        total_distance(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display> {
+        similarity_score(input)
+    }
+}
+
+pub fn calculate_total_distance(filename: &str) -> Result<u32> {
+    total_distance(&Day01::parse_file(filename)?)
+}
+
+pub fn calculate_similarity_score(filename: &str) -> Result<u32> {
+    similarity_score(&Day01::parse_file(filename)?)
+}
+
+fn total_distance((left, right): &(Vec<u32>, Vec<u32>)) -> Result<u32> {
+    let mut left = left.clone();
+    let mut right = right.clone();
+
+    left.sort_unstable();
+    right.sort_unstable();
+
+    Ok(left
+        .iter()
+        .zip(right.iter())
+        .map(|(l, r)| l.abs_diff(*r))
+        .sum())
+}
+
+fn similarity_score((left, right): &(Vec<u32>, Vec<u32>)) -> Result<u32> {
+    Ok(left
+        .iter()
+        .map(|left| {
+            let right = right.iter().filter(|r| *r == left).count() as u32;
+            left * right
+        })
+        .sum())
+}
+
+pub fn parse(data: &str) -> Result<(Vec<u32>, Vec<u32>)> {
+    Ok(data
+        .lines()
+        .map(parse_line)
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .unzip())
+}
+
+fn parse_line(line: &str) -> Result<(u32, u32)> {
+    let (left, right) = line
+        .split_once(char::is_whitespace)
+        .context("expected two values for each line")?;
+    let left = left.trim().parse::<u32>()?;
+    let right = right.trim().parse::<u32>()?;
+    Ok((left, right))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::answers::expected;
+
+    const EXAMPLE: &str = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+
+    #[test]
+    fn test_example() {
+        let input = parse(EXAMPLE).unwrap();
+        assert_eq!(11, total_distance(&input).unwrap());
+        assert_eq!(31, similarity_score(&input).unwrap())
+    }
+
+    #[test]
+    fn test_small_a() {
+        let result = calculate_total_distance("input_small.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(1, "input_small.txt"), result.unwrap().to_string())
+    }
+
+    #[test]
+    fn test_input_a() {
+        let result = calculate_total_distance("input.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(1, "input.txt"), result.unwrap().to_string())
+    }
+
+    #[test]
+    fn test_small_b() {
+        let result = calculate_similarity_score("input_small.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(2, "input_small.txt"), result.unwrap().to_string())
+    }
+
+    #[test]
+    fn test_input_b() {
+        let result = calculate_similarity_score("input.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(2, "input.txt"), result.unwrap().to_string())
+    }
+}
@@ -0,0 +1,60 @@
+use crate::Day02;
+use aoc_common::generate::{Generated, Generator};
+use rand::{rngs::StdRng, Rng};
+
+impl Generator for Day02 {
+    const SIZE: usize = 1000;
+
+    /// `size` reports, a third each are safe, safe with the dampener and unsafe.
+    fn generate(rng: &mut StdRng, size: usize) -> Generated {
+        let mut safe = 0;
+        let mut dampened = 0;
+
+        let lines: Vec<String> = (0..size)
+            .map(|_| {
+                let mut report = safe_report(rng);
+                match rng.gen_range(0..3) {
+                    0 => safe += 1,
+                    // a repeated level is never safe, but removing it is
+                    1 => {
+                        let i = rng.gen_range(0..report.len());
+                        report.insert(i, report[i]);
+                        dampened += 1;
+                    }
+                    // two repeated levels far apart need two removals
+                    _ => {
+                        let last = report.len() - 1;
+                        report.insert(last, report[last]);
+                        report.insert(0, report[0]);
+                    }
+                }
+
+                let levels: Vec<String> = report.iter().map(u32::to_string).collect();
+                levels.join(" ")
+            })
+            .collect();
+
+        Generated::new(lines.join("\n"))
+            .with_part1(safe)
+            .with_part2(safe + dampened)
+    }
+}
+
+/// Five to eight levels that change by one to three in the same direction.
+fn safe_report(rng: &mut StdRng) -> Vec<u32> {
+    let ascending = rng.gen_bool(0.5);
+    let mut level = rng.gen_range(30..70);
+
+    (0..rng.gen_range(5..=8))
+        .map(|_| {
+            let current = level;
+            let step = rng.gen_range(1..=3);
+            level = if ascending {
+                level + step
+            } else {
+                level - step
+            };
+            current
+        })
+        .collect()
+}
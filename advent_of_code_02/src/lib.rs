@@ -0,0 +1,171 @@
+pub mod generate;
+
+use anyhow::Result;
+use aoc_common::{error::parse_number, ParseError, Solution};
+use std::fmt::Display;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Vec<u32>>;
+
+    fn parse(data: &str) -> Result<Self::Input> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display> {
+        Ok(safe_reports(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display> {
+        Ok(safe_reports_with_dampener(input))
+    }
+}
+
+pub fn get_safe_reports(filename: &str) -> Result<usize> {
+    Ok(safe_reports(&Day02::parse_file(filename)?))
+}
+
+pub fn get_safe_reports_with_dampener(filename: &str) -> Result<usize> {
+    Ok(safe_reports_with_dampener(&Day02::parse_file(filename)?))
+}
+
+fn safe_reports(data: &[Vec<u32>]) -> usize {
+    data.iter().filter(|report| is_report_safe(report)).count()
+}
+
+fn safe_reports_with_dampener(data: &[Vec<u32>]) -> usize {
+    data.iter()
+        .filter(|report| is_report_safe_with_dampener(report))
+        .count()
+}
+
+pub fn parse(file: &str) -> Result<Vec<Vec<u32>>> {
+    let data = file
+        .lines()
+        .enumerate()
+        .map(|(index, line)| parse_line(index, line))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(data)
+}
+
+fn parse_line(index: usize, line: &str) -> Result<Vec<u32>, ParseError> {
+    let levels = line
+        .split_whitespace()
+        .map(|level| parse_number(index, line, level))
+        .collect::<Result<Vec<_>, _>>()?;
+    if levels.is_empty() {
+        return Err(ParseError::in_line(index, line, line.len(), "a level"));
+    }
+    Ok(levels)
+}
+
+fn is_report_safe(report: &[u32]) -> bool {
+    // a single level is always in order
+    let [first, second, ..] = *report else {
+        return true;
+    };
+    let order = Order::get_order(first, second);
+    report
+        .iter()
+        .zip(report.iter().skip(1))
+        .all(|(prev, curr)| order.is_in_order(*prev, *curr))
+}
+
+fn is_report_safe_with_dampener(report: &[u32]) -> bool {
+    if is_report_safe(report) {
+        return true;
+    }
+
+    (0..report.len()).any(|skip_index| {
+        // create a new report without the n-th level
+        is_report_safe(
+            &report
+                .iter()
+                .enumerate()
+                .filter_map(|(i, e)| if i == skip_index { None } else { Some(e) })
+                .copied()
+                .collect::<Vec<_>>(),
+        )
+    })
+}
+
+enum Order {
+    Ascending,
+    Descending,
+}
+
+impl Order {
+    fn get_order(a: u32, b: u32) -> Order {
+        if a > b {
+            Order::Descending
+        } else {
+            Order::Ascending
+        }
+    }
+
+    fn is_in_order(&self, a: u32, b: u32) -> bool {
+        let (lower, higher) = match self {
+            Order::Ascending => (a, b),
+            Order::Descending => (b, a),
+        };
+
+        lower < higher && lower + 3 >= higher
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::answers::expected;
+
+    const EXAMPLE: &str = "7 6 4 2 1\n1 2 7 8 9\n9 7 6 2 1\n1 3 2 4 5\n8 6 4 4 1\n1 3 6 7 9\n";
+
+    #[test]
+    fn test_example() {
+        let input = parse(EXAMPLE).unwrap();
+        assert_eq!(2, safe_reports(&input));
+        assert_eq!(4, safe_reports_with_dampener(&input))
+    }
+
+    #[test]
+    fn test_invalid_report() {
+        let result = parse("7 6 4 2 1\n\n9 7 6 2 1\n");
+        let err = result.err().unwrap().downcast::<ParseError>().unwrap();
+        assert_eq!((Some(2), None), (err.line(), err.found()));
+
+        let result = parse("7 6 4 2 1\n1 2 x 8 9\n");
+        let err = result.err().unwrap().downcast::<ParseError>().unwrap();
+        assert_eq!((Some(2), Some(5)), (err.line(), err.column()));
+
+        assert_eq!(1, safe_reports(&parse("5\n").unwrap()));
+    }
+
+    #[test]
+    fn test_small_a() {
+        let result = get_safe_reports("input_small.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(1, "input_small.txt"), result.unwrap().to_string())
+    }
+
+    #[test]
+    fn test_input_a() {
+        let result = get_safe_reports("input.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(1, "input.txt"), result.unwrap().to_string())
+    }
+
+    #[test]
+    fn test_small_b() {
+        let result = get_safe_reports_with_dampener("input_small.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(2, "input_small.txt"), result.unwrap().to_string())
+    }
+
+    #[test]
+    fn test_input_b() {
+        let result = get_safe_reports_with_dampener("input.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(2, "input.txt"), result.unwrap().to_string())
+    }
+}
@@ -0,0 +1,60 @@
+use crate::Day03;
+use aoc_common::generate::{Generated, Generator};
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+
+// neither contains an `m` or a `d`, so it can't complete an instruction
+const GARBAGE: &[u8] = b"!@#$%^&*+-?<>[]{}', ()0123456789whatsforelecuny";
+const CORRUPTED: [&str; 5] = [
+    "mul(4*",
+    "mul[3,7]",
+    "mul ( 2 , 4 )",
+    "mul(5,5]",
+    "mul(1234,5)",
+];
+
+impl Generator for Day03 {
+    const SIZE: usize = 700;
+
+    /// Corrupted memory with `size` valid multiplications in lines of about
+    /// a hundred, mixed with garbage, corrupted instructions, `do()` and `don't()`.
+    fn generate(rng: &mut StdRng, size: usize) -> Generated {
+        let mut memory = String::new();
+        let mut sum = 0;
+        let mut enabled_sum = 0;
+        let mut enabled = true;
+
+        for i in 0..size {
+            if i > 0 && i % 100 == 0 {
+                memory.push('\n');
+            }
+
+            for _ in 0..rng.gen_range(0..8) {
+                memory.push(*GARBAGE.choose(rng).unwrap() as char);
+            }
+
+            match rng.gen_range(0..10) {
+                0 => {
+                    memory += "do()";
+                    enabled = true;
+                }
+                1 => {
+                    memory += "don't()";
+                    enabled = false;
+                }
+                2 => memory += CORRUPTED.choose(rng).unwrap(),
+                _ => {}
+            }
+
+            let (a, b) = (rng.gen_range(1..1000), rng.gen_range(1..1000));
+            memory += &format!("mul({a},{b})");
+            sum += a * b;
+            if enabled {
+                enabled_sum += a * b;
+            }
+        }
+
+        Generated::new(memory)
+            .with_part1(sum)
+            .with_part2(enabled_sum)
+    }
+}
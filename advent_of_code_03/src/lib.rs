@@ -0,0 +1,127 @@
+pub mod generate;
+
+use anyhow::Result;
+use aoc_common::Solution;
+use regex::Regex;
+use std::fmt::Display;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = String;
+
+    fn parse(data: &str) -> Result<Self::Input> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display> {
+        sum(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display> {
+        enabled_sum(input)
+    }
+}
+
+pub fn calculates_sum(filename: &str) -> Result<u32> {
+    sum(&Day03::parse_file(filename)?)
+}
+
+pub fn calculate_enabled_sum(filename: &str) -> Result<u32> {
+    enabled_sum(&Day03::parse_file(filename)?)
+}
+
+fn sum(haystack: &str) -> Result<u32> {
+    let re = Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)")?;
+
+    let sum: u32 = re
+        .captures_iter(haystack)
+        .map(|c| c.extract())
+        .map(|(_, [mult1, mult2])| multiply(mult1, mult2))
+        .collect::<Result<Vec<_>, _>>()?
+        .iter()
+        .sum();
+
+    Ok(sum)
+}
+
+fn enabled_sum(haystack: &str) -> Result<u32> {
+    let re = Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)")?;
+
+    let sum: u32 = re
+        .captures_iter(haystack)
+        .map(|c| (c.get(1).unwrap().start(), c.extract()))
+        .filter_map(|(index, (_, [mult1, mult2]))| {
+            let last_do = haystack[0..index].rfind("do()");
+            let last_dont = haystack[0..index].rfind("don't()");
+            if should_add(last_dont, last_do) {
+                Some(multiply(mult1, mult2))
+            } else {
+                None
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?
+        .iter()
+        .sum();
+
+    Ok(sum)
+}
+
+fn multiply(a: &str, b: &str) -> Result<u32> {
+    let a = a.parse::<u32>()?;
+    let b = b.parse::<u32>()?;
+    Ok(a * b)
+}
+
+fn should_add(last_dont: Option<usize>, last_do: Option<usize>) -> bool {
+    if let Some(last_dont) = last_dont {
+        if let Some(last_do) = last_do {
+            last_dont < last_do // check if last don't() is befor the last do()
+        } else {
+            false // if there is a don't() but no do() then multiplication is disabled
+        }
+    } else {
+        true // if there was no don't() then multiplication is enabled
+    }
+}
+
+pub fn parse(data: &str) -> Result<String> {
+    Ok(data.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::answers::expected;
+
+    #[test]
+    fn test_small_a() {
+        let result = calculates_sum("input_small.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(1, "input_small.txt"), result.unwrap().to_string())
+    }
+
+    #[test]
+    fn test_input_a() {
+        let result = calculates_sum("input.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(1, "input.txt"), result.unwrap().to_string())
+    }
+
+    #[test]
+    fn test_small_b() {
+        let result = calculate_enabled_sum("input_small_b.txt");
+        assert!(result.is_ok());
+        assert_eq!(
+            expected(2, "input_small_b.txt"),
+            result.unwrap().to_string()
+        )
+    }
+
+    #[test]
+    fn test_input_b() {
+        let result = calculate_enabled_sum("input.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(2, "input.txt"), result.unwrap().to_string())
+    }
+}
@@ -0,0 +1,105 @@
+use crate::Day04;
+use aoc_common::{
+    generate::{Generated, Generator},
+    grid::Grid,
+};
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+
+const DIRECTIONS: [(isize, isize); 8] = [
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+];
+
+impl Generator for Day04 {
+    const SIZE: usize = 140;
+
+    /// A word search of `size` by `size` letters with planted `XMAS` words
+    /// and `X-MAS` crosses.
+    ///
+    /// The background only consists of `X` and `S` and every planted word is
+    /// surrounded by a ring of `S`, so no letters of different words can form
+    /// another word.
+    fn generate(rng: &mut StdRng, size: usize) -> Generated {
+        let mut grid: Grid<Option<char>> = Grid::filled(size, size, None);
+        let (mut words, mut crosses) = (0, 0);
+
+        for _ in 0..size * size / 40 {
+            let letters = match rng.gen_bool(0.7) {
+                true => word(rng),
+                false => cross(rng),
+            };
+            let x = rng.gen_range(0..size) as isize;
+            let y = rng.gen_range(0..size) as isize;
+            let cells: Vec<(usize, usize, char)> = letters
+                .iter()
+                .filter_map(|&(dx, dy, c)| {
+                    Some(((x + dx).try_into().ok()?, (y + dy).try_into().ok()?, c))
+                })
+                .filter(|&(x, y, _)| x < size && y < size)
+                .collect();
+
+            let free = cells.len() == letters.len()
+                && cells.iter().all(|&(x, y, _)| {
+                    grid.neighbors8((x, y))
+                        .chain([(x, y)])
+                        .all(|neighbor| grid[neighbor].is_none())
+                });
+            if !free {
+                continue;
+            }
+
+            for &(x, y, _) in &cells {
+                for neighbor in grid.neighbors8((x, y)).collect::<Vec<_>>() {
+                    grid[neighbor] = Some('S');
+                }
+            }
+            for (x, y, c) in cells {
+                grid[(x, y)] = Some(c);
+            }
+            match letters.len() {
+                4 => words += 1,
+                _ => crosses += 1,
+            }
+        }
+
+        let mut input = String::new();
+        for row in grid.rows() {
+            for cell in row {
+                input.push(cell.unwrap_or_else(|| *['X', 'S'].choose(rng).unwrap()));
+            }
+            input.push('\n');
+        }
+
+        Generated::new(input).with_part1(words).with_part2(crosses)
+    }
+}
+
+/// The letters of `XMAS` in a random direction relative to the `X`.
+fn word(rng: &mut StdRng) -> Vec<(isize, isize, char)> {
+    let (dx, dy) = *DIRECTIONS.choose(rng).unwrap();
+    "XMAS"
+        .chars()
+        .zip(0..)
+        .map(|(c, i)| (dx * i, dy * i, c))
+        .collect()
+}
+
+/// Two diagonal `MAS` that cross at the `A`, relative to the `A`.
+fn cross(rng: &mut StdRng) -> Vec<(isize, isize, char)> {
+    let mut cross = vec![(0, 0, 'A')];
+    for (dx, dy) in [(1, 1), (1, -1)] {
+        let (m, s) = match rng.gen_bool(0.5) {
+            true => ((dx, dy), (-dx, -dy)),
+            false => ((-dx, -dy), (dx, dy)),
+        };
+        cross.push((m.0, m.1, 'M'));
+        cross.push((s.0, s.1, 'S'));
+    }
+    cross
+}
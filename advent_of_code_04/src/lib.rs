@@ -0,0 +1,141 @@
+pub mod generate;
+
+use anyhow::Result;
+use aoc_common::{grid::Grid, Point, Solution};
+use std::fmt::Display;
+
+const TOP_LEFT: Point<i32> = Point::new(-1, -1);
+const TOP_RIGHT: Point<i32> = Point::new(1, -1);
+const DOWN_LEFT: Point<i32> = Point::new(-1, 1);
+const DOWN_RIGHT: Point<i32> = Point::new(1, 1);
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Map;
+
+    fn parse(data: &str) -> Result<Self::Input> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display> {
+        Ok(xmas(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display> {
+        Ok(x_mas(input))
+    }
+}
+
+pub fn count_xmas(filename: &str) -> Result<usize> {
+    Ok(xmas(&Day04::parse_file(filename)?))
+}
+
+pub fn count_x_mas(filename: &str) -> Result<usize> {
+    Ok(x_mas(&Day04::parse_file(filename)?))
+}
+
+pub fn parse(data: &str) -> Result<Map> {
+    Map::parse(data)
+}
+
+fn xmas(map: &Map) -> usize {
+    let mut sum = 0;
+    for (x, y) in map.data.positions() {
+        sum += count_xmas_from_pos(map, Point::new(x as i32, y as i32));
+    }
+
+    sum
+}
+
+fn x_mas(map: &Map) -> usize {
+    let mut sum = 0;
+    for (x, y) in map.data.positions_of(&'A') {
+        let pos = Point::new(x as i32, y as i32);
+
+        let mut diagonal1 = [map.get(pos + TOP_LEFT), map.get(pos + DOWN_RIGHT)];
+        let mut diagonal2 = [map.get(pos + TOP_RIGHT), map.get(pos + DOWN_LEFT)];
+
+        diagonal1.sort_unstable();
+        diagonal2.sort_unstable();
+
+        if diagonal1 == [Some('M'), Some('S')] && diagonal2 == [Some('M'), Some('S')] {
+            sum += 1;
+        }
+    }
+
+    sum
+}
+
+fn count_xmas_from_pos(map: &Map, pos: Point<i32>) -> usize {
+    Point::<i32>::ADJACENT
+        .into_iter()
+        .filter(|direction| contains_xmas(map, pos, *direction))
+        .count()
+}
+
+fn contains_xmas(map: &Map, mut pos: Point<i32>, direction: Point<i32>) -> bool {
+    let xmas = ['X', 'M', 'A', 'S'];
+
+    for character in xmas {
+        if map.get(pos) != Some(character) {
+            return false;
+        }
+        pos += direction;
+    }
+
+    true
+}
+
+pub struct Map {
+    data: Grid<char>,
+}
+
+impl Map {
+    fn parse(data: &str) -> Result<Map> {
+        let data = data.parse()?;
+
+        Ok(Map { data })
+    }
+
+    fn get(&self, position: Point<i32>) -> Option<char> {
+        self.data.get_signed(position.x, position.y).copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::answers::expected;
+
+    #[test]
+    fn test_small_a() {
+        let result = count_xmas("input_small.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(1, "input_small.txt"), result.unwrap().to_string())
+    }
+
+    #[test]
+    fn test_input_a() {
+        let result = count_xmas("input.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(1, "input.txt"), result.unwrap().to_string())
+    }
+
+    #[test]
+    fn test_small_b() {
+        let result = count_x_mas("input_small_b.txt");
+        assert!(result.is_ok());
+        assert_eq!(
+            expected(2, "input_small_b.txt"),
+            result.unwrap().to_string()
+        )
+    }
+
+    #[test]
+    fn test_input_b() {
+        let result = count_x_mas("input.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(2, "input.txt"), result.unwrap().to_string())
+    }
+}
@@ -0,0 +1,50 @@
+use crate::Day05;
+use aoc_common::generate::{Generated, Generator};
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+
+impl Generator for Day05 {
+    const SIZE: usize = 200;
+
+    /// Ordering rules for every pair of 49 two digit pages and `size` updates,
+    /// about half of them in the wrong order.
+    fn generate(rng: &mut StdRng, size: usize) -> Generated {
+        let mut order: Vec<u32> = (10..100).collect();
+        order.shuffle(rng);
+        order.truncate(49);
+
+        let mut rules = Vec::new();
+        for (i, before) in order.iter().enumerate() {
+            for after in &order[i + 1..] {
+                rules.push(format!("{before}|{after}"));
+            }
+        }
+        rules.shuffle(rng);
+
+        let (mut valid, mut fixed) = (0, 0);
+        let updates: Vec<String> = (0..size)
+            .map(|_| {
+                let length = 2 * rng.gen_range(2..12) + 1;
+                let mut update: Vec<u32> = order.choose_multiple(rng, length).copied().collect();
+                update.sort_by_key(|page| order.iter().position(|p| p == page));
+                let middle = update[length / 2];
+
+                let sorted = update.clone();
+                if rng.gen_bool(0.5) {
+                    while update == sorted {
+                        update.shuffle(rng);
+                    }
+                    fixed += middle;
+                } else {
+                    valid += middle;
+                }
+
+                let pages: Vec<String> = update.iter().map(u32::to_string).collect();
+                pages.join(",")
+            })
+            .collect();
+
+        Generated::new(format!("{}\n\n{}", rules.join("\n"), updates.join("\n")))
+            .with_part1(valid)
+            .with_part2(fixed)
+    }
+}
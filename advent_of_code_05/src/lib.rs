@@ -0,0 +1,159 @@
+pub mod generate;
+
+use anyhow::{Context, Result};
+use aoc_common::Solution;
+use std::fmt::Display;
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = InputData;
+
+    fn parse(data: &str) -> Result<Self::Input> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display> {
+        Ok(valid_middle_pages(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display> {
+        Ok(fixed_middle_pages(input))
+    }
+}
+
+pub fn middle_page_sum(filename: &str) -> Result<u32> {
+    Ok(valid_middle_pages(&Day05::parse_file(filename)?))
+}
+
+pub fn fix_unordered_pages(filename: &str) -> Result<u32> {
+    Ok(fixed_middle_pages(&Day05::parse_file(filename)?))
+}
+
+fn valid_middle_pages(input: &InputData) -> u32 {
+    input
+        .pages
+        .iter()
+        .filter(|page| is_page_valid(page, &input.rules))
+        .map(|page| page.0.get(page.0.len() / 2).unwrap())
+        .sum()
+}
+
+fn fixed_middle_pages(input: &InputData) -> u32 {
+    input
+        .pages
+        .iter()
+        .filter_map(|page| {
+            if is_page_valid(page, &input.rules) {
+                None
+            } else {
+                Some(order_page(page, &input.rules))
+            }
+        })
+        .map(|page| page.0[page.0.len() / 2])
+        .sum()
+}
+
+pub fn parse(data: &str) -> Result<InputData> {
+    let mut found_blank_line = false;
+
+    let mut rules = Vec::new();
+    let mut pages = Vec::new();
+
+    for line in data.lines() {
+        if line.is_empty() {
+            found_blank_line = true;
+        } else if !found_blank_line {
+            let (pre, succ) = line.split_once('|').context("Rule must contain a '|'")?;
+            let rule = OrderingRule {
+                predecessor: pre.parse()?,
+                successor: succ.parse()?,
+            };
+            rules.push(rule);
+        } else {
+            let page = line
+                .split(',')
+                .map(|s| s.parse::<u32>())
+                .collect::<Result<Vec<_>, _>>()?;
+            pages.push(Page(page));
+        }
+    }
+
+    Ok(InputData { rules, pages })
+}
+
+fn is_page_valid(page: &Page, rules: &[OrderingRule]) -> bool {
+    page.0
+        .iter()
+        .enumerate()
+        .all(|(i, p)| page.0[0..i].iter().all(|c| is_valid(*c, *p, rules)))
+}
+
+fn order_page(page: &Page, rules: &[OrderingRule]) -> Page {
+    let mut ordered_page = page.clone();
+    while !is_page_valid(&ordered_page, rules) {
+        'outer: for (i, p) in ordered_page.0.iter().enumerate() {
+            for (j, c) in ordered_page.0[0..i].iter().enumerate() {
+                if !is_valid(*c, *p, rules) {
+                    ordered_page.0.swap(i, j);
+                    break 'outer;
+                }
+            }
+        }
+    }
+
+    ordered_page.clone()
+}
+
+fn is_valid(from: u32, to: u32, rules: &[OrderingRule]) -> bool {
+    rules
+        .iter()
+        .all(|rule| rule.predecessor != to || rule.successor != from)
+}
+
+pub struct InputData {
+    rules: Vec<OrderingRule>,
+    pages: Vec<Page>,
+}
+
+#[derive(Debug, Clone)]
+struct Page(Vec<u32>);
+
+struct OrderingRule {
+    predecessor: u32,
+    successor: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::answers::expected;
+
+    #[test]
+    fn test_small_a() {
+        let result = middle_page_sum("input_small.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(1, "input_small.txt"), result.unwrap().to_string())
+    }
+
+    #[test]
+    fn test_input_a() {
+        let result = middle_page_sum("input.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(1, "input.txt"), result.unwrap().to_string())
+    }
+
+    #[test]
+    fn test_small_b() {
+        let result = fix_unordered_pages("input_small.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(2, "input_small.txt"), result.unwrap().to_string())
+    }
+
+    #[test]
+    fn test_input_b() {
+        let result = fix_unordered_pages("input.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(2, "input.txt"), result.unwrap().to_string())
+    }
+}
@@ -0,0 +1,69 @@
+use crate::Day06;
+use aoc_common::{
+    generate::{Generated, Generator},
+    grid::Grid,
+    Direction, Point,
+};
+use rand::{rngs::StdRng, Rng};
+use std::collections::HashSet;
+
+impl Generator for Day06 {
+    const SIZE: usize = 130;
+
+    /// A lab of `size` by `size` with scattered obstructions. The guard
+    /// always leaves the lab, maps that trap the guard in a loop are dropped.
+    fn generate(rng: &mut StdRng, size: usize) -> Generated {
+        loop {
+            let blocked = Grid::new(
+                size,
+                size,
+                (0..size * size).map(|_| rng.gen_bool(0.05)).collect(),
+            )
+            .expect("The cells fill the grid");
+            let guard = Point::new(rng.gen_range(0..size), rng.gen_range(0..size));
+            if blocked[guard] {
+                continue;
+            }
+
+            let Some(visited) = patrol(&blocked, guard) else {
+                continue;
+            };
+
+            let mut input = String::new();
+            for (y, row) in blocked.rows().enumerate() {
+                for (x, &blocked) in row.iter().enumerate() {
+                    input.push(match blocked {
+                        _ if Point::new(x, y) == guard => '^',
+                        true => '#',
+                        false => '.',
+                    });
+                }
+                input.push('\n');
+            }
+
+            return Generated::new(input).with_part1(visited);
+        }
+    }
+}
+
+/// The number of positions the guard visits before leaving the lab,
+/// `None` if the guard walks in a loop.
+fn patrol(blocked: &Grid<bool>, mut position: Point<usize>) -> Option<usize> {
+    let mut direction = Direction::Up;
+    let mut states = HashSet::new();
+
+    while states.insert((position, direction)) {
+        let Some(next) = position.step_within(direction, blocked.width(), blocked.height()) else {
+            let positions: HashSet<Point<usize>> = states.iter().map(|(p, _)| *p).collect();
+            return Some(positions.len());
+        };
+
+        if blocked[next] {
+            direction = direction.turn_right();
+        } else {
+            position = next;
+        }
+    }
+
+    None
+}
@@ -0,0 +1,202 @@
+pub mod generate;
+pub mod render;
+
+use anyhow::{bail, Result};
+use aoc_common::{grid::Grid, record::Recorder, Direction, ParseError, Point, Solution};
+use std::fmt::Display;
+use tracing::{debug, trace};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Map;
+
+    fn parse(data: &str) -> Result<Self::Input> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display> {
+        distinct_positions(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display> {
+        obstacles(input)
+    }
+}
+
+pub fn number_distinct_positions(filename: &str) -> Result<usize> {
+    distinct_positions(&Day06::parse_file(filename)?)
+}
+
+pub fn count_obstacles(filename: &str) -> Result<u32> {
+    obstacles(&Day06::parse_file(filename)?)
+}
+
+pub fn parse(data: &str) -> Result<Map> {
+    Map::parse(data)
+}
+
+fn distinct_positions(map: &Map) -> Result<usize> {
+    Ok(patrol(map, &mut Recorder::off())?.visited())
+}
+
+// the map with every position the guard walks over marked as visited
+fn patrol(map: &Map, recorder: &mut Recorder) -> Result<Map> {
+    let mut map = map.clone();
+    let mut guard = Guard::new(&map)?;
+
+    while let Some(pos) = guard.position {
+        trace!(position = %pos, direction = ?guard.direction, "guard step");
+        recorder.step(|| map.frame(&guard));
+        map.visit(pos);
+        guard.go(&map);
+    }
+    recorder.finish(|| map.frame(&guard));
+
+    Ok(map)
+}
+
+fn obstacles(original_map: &Map) -> Result<u32> {
+    let original_guard = Guard::new(original_map)?;
+
+    let mut sum = 0;
+    for (x, y) in original_map.data.positions() {
+        let mut map = original_map.clone();
+        let mut guard = original_guard;
+        let mut visited = Vec::new();
+
+        map.block(Point::new(x, y));
+
+        while let Some(pos) = guard.position {
+            if visited.contains(&(guard.position, guard.direction)) {
+                sum += 1;
+                debug!(obstacle = %Point::new(x, y), loops = sum, "guard is stuck in a loop");
+                break;
+            } else {
+                visited.push((guard.position, guard.direction));
+            }
+
+            map.visit(pos);
+            guard.go(&map);
+        }
+    }
+
+    Ok(sum)
+}
+
+#[derive(Debug, Clone)]
+pub struct Map {
+    data: Grid<Field>,
+}
+
+impl Map {
+    fn parse(file: &str) -> Result<Self> {
+        let data = file.parse()?;
+
+        Ok(Map { data })
+    }
+
+    fn visited(&self) -> usize {
+        self.data.positions_of(&Field::Visited).count()
+    }
+
+    fn get(&self, pos: Point<usize>) -> Field {
+        self.data[pos]
+    }
+
+    fn visit(&mut self, pos: Point<usize>) {
+        self.data[pos] = Field::Visited;
+    }
+
+    fn block(&mut self, pos: Point<usize>) {
+        self.data[pos] = Field::Blocked;
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Guard {
+    position: Option<Point<usize>>,
+    direction: Direction,
+}
+
+impl Guard {
+    fn new(map: &Map) -> Result<Guard> {
+        let Some(position) = map.data.find(&Field::Guard) else {
+            bail!("No guard found")
+        };
+
+        Ok(Guard {
+            position: Some(position.into()),
+            direction: Direction::Up,
+        })
+    }
+
+    fn go(&mut self, map: &Map) {
+        let last_position = self.position.unwrap();
+
+        self.position =
+            last_position.step_within(self.direction, map.data.width(), map.data.height());
+
+        if let Some(pos) = self.position {
+            if map.get(pos) == Field::Blocked {
+                self.direction = self.direction.turn_right();
+                self.position = Some(last_position);
+            }
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum Field {
+    Empty,
+    Visited,
+    Blocked,
+    Guard,
+}
+
+impl TryFrom<char> for Field {
+    type Error = ParseError;
+
+    fn try_from(c: char) -> Result<Self, ParseError> {
+        match c {
+            '.' => Ok(Self::Empty),
+            '#' => Ok(Self::Blocked),
+            '^' => Ok(Self::Guard),
+            _ => Err(ParseError::new(Some(c), "'.', '#' or '^'")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::answers::expected;
+
+    #[test]
+    fn test_small_a() {
+        let result = number_distinct_positions("input_small.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(1, "input_small.txt"), result.unwrap().to_string())
+    }
+
+    #[test]
+    fn test_input_a() {
+        let result = number_distinct_positions("input.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(1, "input.txt"), result.unwrap().to_string())
+    }
+
+    #[test]
+    fn test_small_b() {
+        let result = count_obstacles("input_small.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(2, "input_small.txt"), result.unwrap().to_string())
+    }
+
+    #[test]
+    fn test_input_b() {
+        let result = count_obstacles("input.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(2, "input.txt"), result.unwrap().to_string())
+    }
+}
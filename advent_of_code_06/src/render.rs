@@ -0,0 +1,78 @@
+use crate::{patrol, Day06, Field, Guard, Map};
+use anyhow::Result;
+use aoc_common::{
+    record::{Record, Recorder},
+    render::{Canvas, Cell, Render, DARK, GREEN, GREY, RED},
+};
+
+impl Render for Day06 {
+    /// The path of the guard, starting at its original position.
+    fn render(input: &Self::Input) -> Result<Canvas> {
+        let mut map = patrol(input, &mut Recorder::off())?;
+        if let Some(start) = input.data.find(&Field::Guard) {
+            map.data[start] = Field::Guard;
+        }
+        Ok(map.canvas())
+    }
+}
+
+impl Record for Day06 {
+    /// The guard walking step by step until it leaves the map.
+    fn record(input: &Self::Input, recorder: &mut Recorder) -> Result<()> {
+        patrol(input, recorder)?;
+        Ok(())
+    }
+}
+
+impl Map {
+    // the map with the guard pointing in the direction it walks
+    pub(crate) fn frame(&self, guard: &Guard) -> Canvas {
+        let mut canvas = self.canvas();
+        if let Some(position) = guard.position {
+            canvas[position] = Cell::new(guard.direction.arrow(), RED);
+        }
+        canvas
+    }
+
+    fn canvas(&self) -> Canvas {
+        self.data.map(|field| match field {
+            Field::Empty => Cell::new('.', DARK),
+            Field::Visited => Cell::new('X', GREEN),
+            Field::Blocked => Cell::new('#', GREY),
+            Field::Guard => Cell::new('^', RED),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    #[test]
+    fn test_render() {
+        let map = parse(".#..\n...#\n.^..\n").unwrap();
+        let result = Day06::render(&map);
+        assert!(result.is_ok());
+        assert_eq!(".#..\n.XX#\n.^X.\n", result.unwrap().to_string());
+    }
+
+    #[test]
+    fn test_record() {
+        let map = parse(".#..\n...#\n.^..\n").unwrap();
+        let mut recorder = Recorder::new(2);
+        assert!(Day06::record(&map, &mut recorder).is_ok());
+
+        // turning on the spot is a step of its own
+        let frames: Vec<String> = recorder.frames().iter().map(Canvas::to_string).collect();
+        assert_eq!(
+            vec![
+                ".#..\n...#\n.^..\n",
+                ".#..\n.>.#\n.X..\n",
+                ".#..\n.Xv#\n.X..\n",
+                ".#..\n.XX#\n.XX.\n",
+            ],
+            frames
+        );
+    }
+}
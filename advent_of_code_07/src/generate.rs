@@ -0,0 +1,43 @@
+use crate::Day07;
+use aoc_common::generate::{Generated, Generator};
+use rand::{rngs::StdRng, Rng};
+
+// keeps the intermediate results of the solutions far away from an overflow
+const MAX_TEST_VALUE: u64 = 1_000_000_000_000_000;
+
+impl Generator for Day07 {
+    const SIZE: usize = 850;
+
+    /// `size` equations, each built by combining the numbers with random
+    /// operators, so all of them can be made true with concatenation.
+    fn generate(rng: &mut StdRng, size: usize) -> Generated {
+        let mut lines = Vec::new();
+        let mut sum = 0;
+
+        while lines.len() < size {
+            let values: Vec<u64> = (0..rng.gen_range(2..=8))
+                .map(|_| match rng.gen_bool(0.7) {
+                    true => rng.gen_range(1..10),
+                    false => rng.gen_range(10..1000),
+                })
+                .collect();
+
+            let test_value = values[1..].iter().try_fold(values[0], |result, &value| {
+                match rng.gen_range(0..3) {
+                    0 => result.checked_add(value),
+                    1 => result.checked_mul(value),
+                    _ => format!("{result}{value}").parse().ok(),
+                }
+                .filter(|&result| result < MAX_TEST_VALUE)
+            });
+
+            if let Some(test_value) = test_value {
+                let values: Vec<String> = values.iter().map(u64::to_string).collect();
+                lines.push(format!("{test_value}: {}", values.join(" ")));
+                sum += test_value;
+            }
+        }
+
+        Generated::new(lines.join("\n")).with_part2(sum)
+    }
+}
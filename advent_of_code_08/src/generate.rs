@@ -0,0 +1,29 @@
+use crate::Day08;
+use aoc_common::generate::{Generated, Generator};
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+
+const FREQUENCIES: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+impl Generator for Day08 {
+    const SIZE: usize = 50;
+
+    /// A map of `size` by `size` with three to five antennas for every
+    /// frequency that fits on the map.
+    fn generate(rng: &mut StdRng, size: usize) -> Generated {
+        let mut map = vec![vec!['.'; size]; size];
+        let frequencies = FREQUENCIES.len().min(size * size / 40);
+
+        for &frequency in &FREQUENCIES[..frequencies] {
+            for _ in 0..rng.gen_range(3..=5) {
+                let row = map.choose_mut(rng).unwrap();
+                let cell = row.choose_mut(rng).unwrap();
+                if *cell == '.' {
+                    *cell = frequency as char;
+                }
+            }
+        }
+
+        let lines: Vec<String> = map.into_iter().map(String::from_iter).collect();
+        Generated::new(lines.join("\n"))
+    }
+}
@@ -0,0 +1,177 @@
+pub mod generate;
+
+use anyhow::Result;
+use aoc_common::{grid::Grid, Point, Solution};
+use itertools::Itertools;
+use std::{collections::BTreeMap, fmt::Display};
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Map;
+
+    fn parse(data: &str) -> Result<Self::Input> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display> {
+        anodes_of_antennas(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display> {
+        anodes_of_resonance_antennas(input)
+    }
+}
+
+pub fn count_anodes_of_antennas(filename: &str) -> Result<usize> {
+    anodes_of_antennas(&Day08::parse_file(filename)?)
+}
+
+pub fn count_anodes_of_resonance_antennas(filename: &str) -> Result<usize> {
+    anodes_of_resonance_antennas(&Day08::parse_file(filename)?)
+}
+
+fn anodes_of_antennas(map: &Map) -> Result<usize> {
+    let antennas = Antennas::new(map);
+
+    let mut anodes: Vec<Position> = Vec::new();
+    for (_, positions) in antennas.0 {
+        let mut current: Vec<Position> = positions
+            .iter()
+            .permutations(2)
+            .flat_map(|positions| calculate_anodes(*positions[0], *positions[1]))
+            .filter(|anode| map.is_valid(*anode))
+            .collect();
+
+        anodes.append(&mut current);
+    }
+
+    let count = anodes.iter().unique().count();
+
+    Ok(count)
+}
+
+fn calculate_anodes(a: Position, b: Position) -> [Position; 2] {
+    let first = a * 2 - b;
+    let second = b * 2 - a;
+    [first, second]
+}
+
+fn anodes_of_resonance_antennas(map: &Map) -> Result<usize> {
+    let antennas = Antennas::new(map);
+
+    let mut anodes: Vec<Position> = Vec::new();
+    for (_, positions) in antennas.0 {
+        let mut current: Vec<Position> = positions
+            .iter()
+            .permutations(2)
+            .flat_map(|positions| calculate_resonance_anodes(map, *positions[0], *positions[1]))
+            .collect();
+
+        anodes.append(&mut current);
+    }
+
+    let count = anodes.iter().unique().count();
+
+    Ok(count)
+}
+
+fn calculate_resonance_anodes(map: &Map, a: Position, b: Position) -> Vec<Position> {
+    let mut anodes = Vec::new();
+    anodes.append(&mut calculate_resonance_anodes_unidirectinal(map, a, b));
+    anodes.append(&mut calculate_resonance_anodes_unidirectinal(map, b, a));
+    anodes
+}
+
+fn calculate_resonance_anodes_unidirectinal(
+    map: &Map,
+    start: Position,
+    end: Position,
+) -> Vec<Position> {
+    let mut anodes = Vec::new();
+    let diff = start - end;
+    for i in 1.. {
+        let anode = start - diff * i;
+        if map.is_valid(anode) {
+            anodes.push(anode);
+        } else {
+            break;
+        }
+    }
+    anodes
+}
+
+pub fn parse(file: &str) -> Result<Map> {
+    let data = file.parse()?;
+
+    Ok(Map { data })
+}
+
+type Position = Point<i32>;
+
+pub struct Map {
+    data: Grid<char>,
+}
+
+impl Map {
+    fn is_valid(&self, pos: Position) -> bool {
+        self.data.contains(pos.x, pos.y)
+    }
+}
+
+struct Antennas(BTreeMap<char, Vec<Position>>);
+
+impl Antennas {
+    fn new(map: &Map) -> Self {
+        let mut antennas: BTreeMap<char, Vec<Position>> = BTreeMap::new();
+
+        for ((x, y), c) in map.data.iter() {
+            if *c != '.' {
+                let position = Position::new(x as i32, y as i32);
+                let values = antennas.get_mut(c);
+
+                if let Some(values) = values {
+                    values.push(position);
+                } else {
+                    antennas.insert(*c, vec![position]);
+                }
+            }
+        }
+
+        Self(antennas)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::answers::expected;
+
+    #[test]
+    fn test_small_a() {
+        let result = count_anodes_of_antennas("input_small.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(1, "input_small.txt"), result.unwrap().to_string())
+    }
+
+    #[test]
+    fn test_input_a() {
+        let result = count_anodes_of_antennas("input.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(1, "input.txt"), result.unwrap().to_string())
+    }
+
+    #[test]
+    fn test_small_b() {
+        let result = count_anodes_of_resonance_antennas("input_small.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(2, "input_small.txt"), result.unwrap().to_string())
+    }
+
+    #[test]
+    fn test_input_b() {
+        let result = count_anodes_of_resonance_antennas("input.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(2, "input.txt"), result.unwrap().to_string())
+    }
+}
@@ -0,0 +1,22 @@
+use crate::Day09;
+use aoc_common::generate::{Generated, Generator};
+use rand::{rngs::StdRng, Rng};
+
+impl Generator for Day09 {
+    const SIZE: usize = 10000;
+
+    /// A disk map of `size` files with one to nine blocks each, separated by
+    /// up to nine free blocks.
+    fn generate(rng: &mut StdRng, size: usize) -> Generated {
+        let mut disk_map = String::new();
+
+        for file in 0..size {
+            if file > 0 {
+                disk_map.push_str(&rng.gen_range(0..10).to_string());
+            }
+            disk_map.push_str(&rng.gen_range(1..10).to_string());
+        }
+
+        Generated::new(disk_map)
+    }
+}
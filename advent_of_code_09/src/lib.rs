@@ -0,0 +1,321 @@
+pub mod generate;
+pub mod render;
+
+use anyhow::Result;
+use aoc_common::{record::Recorder, ParseError, Solution};
+use core::fmt;
+use std::{
+    fmt::{Display, Formatter},
+    iter,
+};
+use tracing::trace;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Filesystem;
+
+    fn parse(data: &str) -> Result<Self::Input> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display> {
+        Ok(file_checksum(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display> {
+        Ok(file_checksum_ext(input))
+    }
+}
+
+pub fn get_file_checksum(filename: &str) -> Result<u128> {
+    Ok(file_checksum(&Day09::parse_file(filename)?))
+}
+
+pub fn get_file_checksum_ext(filename: &str) -> Result<u128> {
+    Ok(file_checksum_ext(&Day09::parse_file(filename)?))
+}
+
+pub fn parse(data: &str) -> Result<Filesystem> {
+    create_filesystem(data)
+}
+
+fn file_checksum(filesystem: &Filesystem) -> u128 {
+    let defragmented = defragment_filesystem(filesystem.clone());
+    trace!(filesystem = ?defragmented, "defragmented blocks");
+
+    calculate_checksum(&defragmented)
+}
+
+fn file_checksum_ext(filesystem: &Filesystem) -> u128 {
+    let defragmented = defragment_filesystem_ext(filesystem.clone(), &mut Recorder::off());
+    trace!(filesystem = ?defragmented, "defragmented files");
+
+    calculate_checksum(&defragmented)
+}
+
+fn create_filesystem(file_string: &str) -> Result<Filesystem> {
+    let mut data = Vec::new();
+    let mut space = Filespace::Free;
+    let mut id = 0;
+    for (index, line) in file_string.lines().enumerate() {
+        for (offset, c) in line.char_indices() {
+            let n = c
+                .to_digit(10)
+                .ok_or_else(|| ParseError::in_line(index, line, offset, "a digit"))?;
+            space = space.next(id);
+            data.extend(iter::repeat_n(space, n as usize));
+
+            if let Filespace::File(_) = space {
+                id += 1;
+            }
+        }
+    }
+    Ok(Filesystem { data })
+}
+
+fn defragment_filesystem(mut filesystem: Filesystem) -> Filesystem {
+    let data = &mut filesystem.data;
+    let mut free_id = 0;
+    // one past the last block that might hold a file
+    let mut last_id = data.len();
+
+    loop {
+        // iterate free pointer until there is no file
+        while free_id < last_id && matches!(data[free_id], Filespace::File(_)) {
+            free_id += 1;
+        }
+
+        // iterate last pointer to the left until file was found
+        while last_id > free_id && matches!(data[last_id - 1], Filespace::Free) {
+            last_id -= 1;
+        }
+
+        if free_id >= last_id {
+            break;
+        }
+
+        // swap memory to free space
+        data[free_id] = data[last_id - 1];
+        data[last_id - 1] = Filespace::Free;
+    }
+
+    filesystem
+}
+
+fn defragment_filesystem_ext(mut filesystem: Filesystem, recorder: &mut Recorder) -> Filesystem {
+    recorder.step(|| filesystem.canvas());
+
+    // every file is moved at most once, from the last file to the first
+    let mut end = filesystem.data.len();
+    while let Some(file) = end
+        .checked_sub(1)
+        .and_then(|index| get_next_file(&filesystem, index))
+    {
+        // find space that is big enough for the file
+        let space = get_free_space(&filesystem, file.length);
+        if let Some(space) = space {
+            if space.start < file.start {
+                move_file(&mut filesystem, &file, &space);
+            }
+        }
+        recorder.step(|| filesystem.canvas());
+
+        end = file.start;
+    }
+    recorder.finish(|| filesystem.canvas());
+
+    filesystem
+}
+
+fn get_next_file(filesystem: &Filesystem, index: usize) -> Option<File> {
+    let mut end_index = index;
+    // iterate from the end until next file was found
+    while let Filespace::Free = filesystem.data[end_index] {
+        if end_index == 0 {
+            return None;
+        }
+
+        end_index -= 1;
+    }
+
+    // get file id
+    let Filespace::File(file_id) = filesystem.data[end_index] else {
+        return None;
+    };
+
+    // iterate to find the start of the file
+    let mut start_index = end_index;
+    while start_index > 0
+        && matches!(filesystem.data[start_index - 1], Filespace::File(id) if id == file_id)
+    {
+        start_index -= 1;
+    }
+
+    Some(File {
+        file_space: Filespace::File(file_id),
+        start: start_index,
+        length: end_index - start_index + 1,
+    })
+}
+
+fn get_free_space(filesystem: &Filesystem, length: usize) -> Option<File> {
+    let mut index = 0;
+    while let Some(space) = get_next_space(filesystem, index) {
+        if space.length >= length {
+            return Some(space);
+        }
+        index = space.start + space.length;
+    }
+    None
+}
+
+// the next free space at or after `start`, `None` if there is none
+fn get_next_space(filesystem: &Filesystem, mut start: usize) -> Option<File> {
+    let data = &filesystem.data;
+
+    // iterate free pointer until there is no file
+    while start < data.len() && matches!(data[start], Filespace::File(_)) {
+        start += 1;
+    }
+
+    let mut end = start;
+    while end < data.len() && matches!(data[end], Filespace::Free) {
+        end += 1;
+    }
+
+    (start < end).then_some(File {
+        file_space: Filespace::Free,
+        start,
+        length: end - start,
+    })
+}
+
+fn move_file(filesystem: &mut Filesystem, file: &File, space: &File) {
+    for i in space.start..space.start + file.length {
+        filesystem.data[i] = file.file_space;
+    }
+
+    for i in file.start..file.start + file.length {
+        filesystem.data[i] = Filespace::Free;
+    }
+}
+
+fn calculate_checksum(filesystem: &Filesystem) -> u128 {
+    let mut checksum: u128 = 0;
+    for (i, f) in filesystem.data.iter().enumerate() {
+        if let Filespace::File(f) = f {
+            checksum += i as u128 * *f as u128;
+        }
+    }
+
+    checksum
+}
+
+#[derive(Clone, Copy)]
+enum Filespace {
+    File(u32),
+    Free,
+}
+
+impl Filespace {
+    fn next(&self, id: u32) -> Self {
+        match self {
+            Self::File(_) => Self::Free,
+            Self::Free => Self::File(id),
+        }
+    }
+}
+
+impl fmt::Debug for Filespace {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let data = match self {
+            Filespace::File(c) => c.to_string(),
+            Filespace::Free => ".".to_string(),
+        };
+        write!(f, "{data}")
+    }
+}
+
+#[derive(Debug)]
+struct File {
+    file_space: Filespace,
+    start: usize,
+    length: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct Filesystem {
+    data: Vec<Filespace>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{answers::expected, solution::solve};
+
+    const EXAMPLE: &str = "2333133121414131402";
+
+    #[test]
+    fn test_example() {
+        let input = parse(EXAMPLE).unwrap();
+        assert_eq!(1928, file_checksum(&input));
+        assert_eq!(2858, file_checksum_ext(&input))
+    }
+
+    #[test]
+    fn test_untidy_input() {
+        let untidy = format!("\u{feff}{EXAMPLE} \r\n\r\n");
+        let result = solve::<Day09>(1, &untidy);
+        assert!(result.is_ok());
+        assert_eq!("1928", result.unwrap());
+
+        let err = parse("23x3").unwrap_err().downcast::<ParseError>().unwrap();
+        assert_eq!(Some('x'), err.found());
+        assert_eq!(Some(1), err.line());
+        assert_eq!(Some(3), err.column());
+    }
+
+    #[test]
+    fn test_short_disk_maps() {
+        for (disk_map, checksum, checksum_ext) in [
+            ("", 0, 0),
+            ("0", 0, 0),
+            ("1", 0, 0),
+            ("10", 0, 0),
+            ("12345", 60, 132),
+        ] {
+            let input = parse(disk_map).unwrap();
+            assert_eq!(checksum, file_checksum(&input), "{disk_map:?}");
+            assert_eq!(checksum_ext, file_checksum_ext(&input), "{disk_map:?}");
+        }
+    }
+
+    #[test]
+    fn test_small_a() {
+        let result = get_file_checksum("input_small.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(1, "input_small.txt"), result.unwrap().to_string())
+    }
+
+    #[test]
+    fn test_input_a() {
+        let result = get_file_checksum("input.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(1, "input.txt"), result.unwrap().to_string())
+    }
+
+    #[test]
+    fn test_small_b() {
+        let result = get_file_checksum_ext("input_small.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(2, "input_small.txt"), result.unwrap().to_string())
+    }
+
+    #[test]
+    fn test_input_b() {
+        let result = get_file_checksum_ext("input.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(2, "input.txt"), result.unwrap().to_string())
+    }
+}
@@ -0,0 +1,61 @@
+use crate::{defragment_filesystem_ext, Day09, Filespace, Filesystem};
+use anyhow::Result;
+use aoc_common::{
+    grid::Grid,
+    record::{Record, Recorder},
+    render::{palette, Canvas, Cell, BLACK, DARK},
+};
+
+impl Record for Day09 {
+    /// The disk after every file the defragmentation tried to move.
+    fn record(input: &Self::Input, recorder: &mut Recorder) -> Result<()> {
+        defragment_filesystem_ext(input.clone(), recorder);
+        Ok(())
+    }
+}
+
+impl Filesystem {
+    // the blocks wrapped into rows of a roughly square canvas, every file in
+    // its own color and marked with the last digit of its id
+    pub(crate) fn canvas(&self) -> Canvas {
+        let width = (self.data.len() as f64).sqrt().ceil().max(1.0) as usize;
+        let height = self.data.len().div_ceil(width);
+
+        let mut canvas = Grid::filled(width, height, Cell::new(' ', BLACK));
+        for (index, space) in self.data.iter().enumerate() {
+            canvas[(index % width, index / width)] = match space {
+                Filespace::File(id) => {
+                    let digit = char::from_digit(id % 10, 10).unwrap_or('#');
+                    Cell::new(digit, palette(*id as usize))
+                }
+                Filespace::Free => Cell::new('.', DARK),
+            };
+        }
+        canvas
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    #[test]
+    fn test_record() {
+        let filesystem = parse("2333133121414131402").unwrap();
+        let mut recorder = Recorder::new(1);
+        assert!(Day09::record(&filesystem, &mut recorder).is_ok());
+
+        // from the fragmented disk to the one of the puzzle description
+        let frames = recorder.frames();
+        assert!(frames.len() > 10);
+        assert_eq!(
+            "00...11\n1...2..\n.333.44\n.5555.6\n666.777\n.888899\n",
+            frames[0].to_string()
+        );
+        assert_eq!(
+            "0099211\n1777.44\n.333...\n.5555.6\n666....\n.8888..\n",
+            frames.last().unwrap().to_string()
+        );
+    }
+}
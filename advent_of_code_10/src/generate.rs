@@ -0,0 +1,34 @@
+use crate::Day10;
+use aoc_common::generate::{Generated, Generator};
+use rand::{rngs::StdRng, Rng};
+
+impl Generator for Day10 {
+    const SIZE: usize = 50;
+
+    /// A topographic map of `size` by `size` with hills around random peaks,
+    /// so that there are plenty of hiking trails, and a bit of noise.
+    fn generate(rng: &mut StdRng, size: usize) -> Generated {
+        let peaks: Vec<(usize, usize)> = (0..size * size / 50)
+            .map(|_| (rng.gen_range(0..size), rng.gen_range(0..size)))
+            .collect();
+
+        let mut lines = Vec::new();
+        for y in 0..size {
+            let mut line = String::new();
+            for x in 0..size {
+                let height = match rng.gen_bool(0.1) {
+                    true => rng.gen_range(0..10),
+                    false => peaks
+                        .iter()
+                        .map(|&(px, py)| 9_usize.saturating_sub(px.abs_diff(x) + py.abs_diff(y)))
+                        .max()
+                        .unwrap_or(0),
+                };
+                line.push_str(&height.to_string());
+            }
+            lines.push(line);
+        }
+
+        Generated::new(lines.join("\n"))
+    }
+}
@@ -0,0 +1,174 @@
+pub mod generate;
+
+use anyhow::Result;
+use aoc_common::{grid::Grid, ParseError, Point, Solution};
+use itertools::Itertools;
+use std::fmt::Display;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Map;
+
+    fn parse(data: &str) -> Result<Self::Input> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display> {
+        trailheads(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display> {
+        trailhead_ratings(input)
+    }
+}
+
+pub fn calculate_trailheads(filename: &str) -> Result<usize> {
+    trailheads(&Day10::parse_file(filename)?)
+}
+
+pub fn calculate_trailhead_ratings(filename: &str) -> Result<usize> {
+    trailhead_ratings(&Day10::parse_file(filename)?)
+}
+
+pub fn parse(data: &str) -> Result<Map> {
+    Map::parse(data)
+}
+
+fn trailheads(map: &Map) -> Result<usize> {
+    let trailheads = map
+        .get_starting_points()
+        .iter()
+        .map(|&start| map.get_trailheads(start))
+        .sum();
+
+    Ok(trailheads)
+}
+
+fn trailhead_ratings(map: &Map) -> Result<usize> {
+    let trailheads = map
+        .get_starting_points()
+        .iter()
+        .map(|&start| map.get_trailhead_ratings(start))
+        .sum();
+
+    Ok(trailheads)
+}
+
+type Position = Point<i32>;
+
+pub struct Map {
+    data: Grid<i8>,
+}
+
+impl Map {
+    fn parse(file: &str) -> Result<Self> {
+        let data = Grid::try_parse_with(file, |c| {
+            c.to_digit(10)
+                .map(|height| height as i8)
+                .ok_or(ParseError::new(Some(c), "a digit"))
+        })?;
+
+        Ok(Map { data })
+    }
+
+    fn get(&self, pos: Position) -> Option<i8> {
+        self.data.get_signed(pos.x, pos.y).copied()
+    }
+
+    fn get_starting_points(&self) -> Vec<Position> {
+        self.data
+            .positions_of(&0)
+            .map(|(x, y)| Position::new(x as i32, y as i32))
+            .collect()
+    }
+
+    fn get_trailheads(&self, start: Position) -> usize {
+        self.get_trailheads_internal(start, -1)
+            .iter()
+            .unique()
+            .count()
+    }
+
+    fn get_trailhead_ratings(&self, start: Position) -> usize {
+        self.get_trailhead_ratings_internal(start, -1)
+    }
+
+    fn get_trailheads_internal(&self, position: Position, previous: i8) -> Vec<Position> {
+        let value = self.get(position);
+        let mut trailheads = Vec::new();
+        if let Some(value) = value {
+            if value == previous + 1 {
+                if value == 9 {
+                    trailheads.push(position);
+                } else {
+                    for next in position.neighbors4() {
+                        let mut th = self.get_trailheads_internal(next, value);
+                        trailheads.append(&mut th);
+                    }
+                }
+            }
+        }
+        trailheads
+    }
+
+    fn get_trailhead_ratings_internal(&self, position: Position, previous: i8) -> usize {
+        let value = self.get(position);
+        if let Some(value) = value {
+            if value != previous + 1 {
+                0
+            } else if value == 9 {
+                1
+            } else {
+                position
+                    .neighbors4()
+                    .map(|next| self.get_trailhead_ratings_internal(next, value))
+                    .sum()
+            }
+        } else {
+            0
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::answers::expected;
+
+    #[test]
+    fn test_invalid_height() {
+        let result = parse("0123\n1x34\n");
+        let err = result.err().unwrap().downcast::<ParseError>().unwrap();
+        assert_eq!(Some('x'), err.found());
+        assert_eq!((Some(2), Some(2)), (err.line(), err.column()))
+    }
+
+    #[test]
+    fn test_small_a() {
+        let result = calculate_trailheads("input_small.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(1, "input_small.txt"), result.unwrap().to_string())
+    }
+
+    #[test]
+    fn test_input_a() {
+        let result = calculate_trailheads("input.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(1, "input.txt"), result.unwrap().to_string())
+    }
+
+    #[test]
+    fn test_small_b() {
+        let result = calculate_trailhead_ratings("input_small.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(2, "input_small.txt"), result.unwrap().to_string())
+    }
+
+    #[test]
+    fn test_input_b() {
+        let result = calculate_trailhead_ratings("input.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(2, "input.txt"), result.unwrap().to_string())
+    }
+}
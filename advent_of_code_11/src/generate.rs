@@ -0,0 +1,19 @@
+use crate::Day11;
+use aoc_common::generate::{Generated, Generator};
+use rand::{rngs::StdRng, Rng};
+
+impl Generator for Day11 {
+    const SIZE: usize = 8;
+
+    /// A line of `size` stones with up to seven digits.
+    fn generate(rng: &mut StdRng, size: usize) -> Generated {
+        let stones: Vec<String> = (0..size)
+            .map(|_| {
+                let digits = rng.gen_range(1..=7);
+                rng.gen_range(0..10_u64.pow(digits)).to_string()
+            })
+            .collect();
+
+        Generated::new(stones.join(" "))
+    }
+}
@@ -0,0 +1,184 @@
+pub mod generate;
+
+use anyhow::Result;
+use aoc_common::Solution;
+use std::{collections::BTreeMap, fmt::Display};
+use tracing::debug;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<u64>;
+
+    fn parse(data: &str) -> Result<Self::Input> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display> {
+        stone_count(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display> {
+        stone_count_opt(input)
+    }
+}
+
+pub fn count_stones(filename: &str) -> Result<usize> {
+    stone_count(&Day11::parse_file(filename)?)
+}
+
+pub fn count_stones_opt(filename: &str) -> Result<u64> {
+    stone_count_opt(&Day11::parse_file(filename)?)
+}
+
+fn stone_count(stones: &[u64]) -> Result<usize> {
+    Ok(blink(stones, 25))
+}
+
+fn stone_count_opt(stones: &[u64]) -> Result<u64> {
+    Ok(blink_opt(stones, 75))
+}
+
+fn blink(stones: &[u64], blinks: usize) -> usize {
+    let mut stones = stones.to_vec();
+
+    for _ in 0..blinks {
+        stones = apply_stone_behavior(stones);
+    }
+
+    stones.len()
+}
+
+fn blink_opt(initial: &[u64], blinks: usize) -> u64 {
+    let mut stones = BTreeMap::new();
+    for &stone in initial {
+        *stones.entry(stone).or_insert(0) += 1;
+    }
+
+    let mut memory = BTreeMap::new();
+
+    for i in 0..blinks {
+        stones = apply_stone_behavior_opt(stones, &mut memory);
+        debug!(
+            blink = i + 1,
+            stones = get_stone_count(&stones),
+            distinct = stones.len(),
+            "blinked"
+        );
+    }
+
+    get_stone_count(&stones)
+}
+
+pub fn parse(data: &str) -> Result<Vec<u64>> {
+    let stones = data
+        .split_whitespace()
+        .map(|v| v.parse::<u64>())
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(stones)
+}
+
+fn apply_stone_behavior(current: Vec<u64>) -> Vec<u64> {
+    let mut result = Vec::new();
+    for stone in current {
+        let mut next = match stone {
+            0 => vec![1],
+            x if has_even_digits(x) => slit_stone(x),
+            y => vec![y * 2024],
+        };
+        result.append(&mut next);
+    }
+
+    result
+}
+
+fn apply_stone_behavior_opt(
+    current: BTreeMap<u64, u64>,
+    memory: &mut BTreeMap<u64, Vec<u64>>,
+) -> BTreeMap<u64, u64> {
+    let mut result: BTreeMap<u64, u64> = BTreeMap::new();
+    for (stone, n) in current.iter() {
+        let stones = if let Some(stones) = memory.get(stone) {
+            stones.clone()
+        } else {
+            let stones = apply_stone_behavior(vec![*stone]);
+            memory.insert(*stone, stones.clone());
+            stones
+        };
+
+        for stone in stones.iter() {
+            if let Some(count) = result.get_mut(stone) {
+                *count += n;
+            } else {
+                result.insert(*stone, *n);
+            }
+        }
+    }
+
+    result
+}
+
+fn get_stone_count(stones: &BTreeMap<u64, u64>) -> u64 {
+    stones.values().sum()
+}
+
+fn has_even_digits(value: u64) -> bool {
+    let digits = value.checked_ilog10().unwrap_or(0) + 1;
+    digits.is_multiple_of(2)
+}
+
+fn slit_stone(value: u64) -> Vec<u64> {
+    let digits = value.checked_ilog10().unwrap_or(0) + 1;
+    let divider = 10_u64.pow(digits / 2);
+    let left = value / divider;
+    let right = value % divider;
+
+    vec![left, right]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::answers::expected;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = "125 17\n";
+
+    #[test]
+    fn test_example_reader() {
+        let input = Day11::parse_reader(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(55312, stone_count(&input).unwrap())
+    }
+
+    #[test]
+    fn test_small_a() {
+        let result = count_stones("input_small.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(1, "input_small.txt"), result.unwrap().to_string())
+    }
+
+    #[test]
+    fn test_input_a() {
+        let result = count_stones("input.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(1, "input.txt"), result.unwrap().to_string())
+    }
+
+    #[test]
+    fn test_input_b() {
+        let result = count_stones_opt("input.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(2, "input.txt"), result.unwrap().to_string())
+    }
+
+    proptest! {
+        #[test]
+        fn test_blink_opt_matches_blink(
+            // small stones repeat often, which the count map has to merge
+            stones in prop::collection::vec(prop_oneof![0..10_u64, 0..100_000_u64], 0..8),
+            blinks in 0..20_usize,
+        ) {
+            prop_assert_eq!(blink(&stones, blinks) as u64, blink_opt(&stones, blinks));
+        }
+    }
+}
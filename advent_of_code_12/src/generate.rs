@@ -0,0 +1,33 @@
+use crate::Day12;
+use aoc_common::generate::{Generated, Generator};
+use rand::{rngs::StdRng, Rng};
+
+impl Generator for Day12 {
+    const SIZE: usize = 140;
+
+    /// A garden of `size` by `size` plots. Every plot belongs to the closest
+    /// of a few random seeds, each seed growing a random plant.
+    fn generate(rng: &mut StdRng, size: usize) -> Generated {
+        let seeds: Vec<(usize, usize, char)> = (0..(size * size / 25).max(1))
+            .map(|_| {
+                let plant = rng.gen_range(b'A'..=b'Z') as char;
+                (rng.gen_range(0..size), rng.gen_range(0..size), plant)
+            })
+            .collect();
+
+        let mut lines = Vec::new();
+        for y in 0..size {
+            let line: String = (0..size)
+                .map(|x| {
+                    let closest = seeds
+                        .iter()
+                        .min_by_key(|(sx, sy, _)| sx.abs_diff(x).pow(2) + sy.abs_diff(y).pow(2));
+                    closest.map_or('A', |&(_, _, plant)| plant)
+                })
+                .collect();
+            lines.push(line);
+        }
+
+        Generated::new(lines.join("\n"))
+    }
+}
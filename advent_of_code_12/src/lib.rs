@@ -0,0 +1,283 @@
+pub mod generate;
+pub mod render;
+
+use anyhow::Result;
+use aoc_common::{grid::Grid, Direction, Point, Solution};
+use std::{collections::VecDeque, fmt::Display};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Map;
+
+    fn parse(data: &str) -> Result<Self::Input> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display> {
+        fencing_price(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display> {
+        fencing_price_ext(input)
+    }
+}
+
+pub fn calculate_fencing_price(filename: &str) -> Result<usize> {
+    fencing_price(&Day12::parse_file(filename)?)
+}
+
+pub fn calculate_fencing_price_ext(filename: &str) -> Result<usize> {
+    fencing_price_ext(&Day12::parse_file(filename)?)
+}
+
+fn fencing_price(map: &Map) -> Result<usize> {
+    let price = get_regions_from_map(map.clone())
+        .iter()
+        .map(|region| region.perimeter * region.area)
+        .sum();
+
+    Ok(price)
+}
+
+fn fencing_price_ext(map: &Map) -> Result<usize> {
+    let price = get_regions_from_map(map.clone())
+        .iter()
+        .map(|region| region.sides * region.area)
+        .sum();
+
+    Ok(price)
+}
+
+fn get_regions_from_map(mut map: Map) -> Vec<Region> {
+    let mut regions = Vec::new();
+    for (x, y) in map.data.positions() {
+        if let Field::Garden(key) = map.data[(x, y)] {
+            let region = get_region(&map, Position::new(x as i32, y as i32), key);
+            for pos in &region.positions {
+                map.visit(*pos);
+            }
+            regions.push(region);
+        }
+    }
+    regions
+}
+
+pub fn parse(file: &str) -> Result<Map> {
+    let data = Grid::parse_with(file, Field::Garden)?;
+
+    Ok(Map { data })
+}
+
+fn get_region(map: &Map, pos: Position, key: char) -> Region {
+    let positions = get_region_positions(map, pos, key);
+    let area = positions.len();
+    let perimeter = get_region_perimeter(&positions);
+    let sides = get_region_sides(&positions);
+    Region {
+        positions,
+        area,
+        perimeter,
+        sides,
+    }
+}
+
+fn get_region_positions(map: &Map, pos: Position, key: char) -> Vec<Position> {
+    let mut closed_map = Vec::new();
+    let mut open_map = VecDeque::from([pos]);
+
+    while let Some(pos) = open_map.pop_front() {
+        closed_map.push(pos);
+
+        for direction in Direction::ALL.iter() {
+            let next = pos.step(*direction);
+            if let Some(Field::Garden(f)) = map.get_pos(next) {
+                if f == key && !closed_map.contains(&next) && !open_map.contains(&next) {
+                    open_map.push_back(next);
+                }
+            }
+        }
+    }
+
+    closed_map
+}
+
+fn get_region_perimeter(positions: &Vec<Position>) -> usize {
+    let mut perimeter = 0;
+    for pos in positions {
+        for direction in Direction::ALL.iter() {
+            let near = pos.step(*direction);
+            if !positions.contains(&near) {
+                perimeter += 1;
+            }
+        }
+    }
+
+    perimeter
+}
+
+fn get_region_sides(positions: &Vec<Position>) -> usize {
+    // get all sides from positions
+    let mut sides = Vec::new();
+    for pos in positions {
+        for direction in Direction::ALL.iter() {
+            let near = pos.step(*direction);
+            if !positions.contains(&near) {
+                sides.push((*direction, *pos));
+            }
+        }
+    }
+
+    let mut sum = 0;
+    for direction in Direction::ALL.iter() {
+        let positions: Vec<Position> = sides
+            .iter()
+            .filter(|(d, _)| d == direction)
+            .map(|(_, position)| *position)
+            .collect();
+
+        if *direction == Direction::Up || *direction == Direction::Down {
+            let min = positions.iter().map(|pos| pos.y).min().unwrap();
+            let max = positions.iter().map(|pos| pos.y).max().unwrap();
+            for y in min..=max {
+                let x_positions: Vec<i32> = positions
+                    .iter()
+                    .filter(|pos| pos.y == y)
+                    .map(|pos| pos.x)
+                    .collect();
+                sum += calculate_sections(x_positions);
+            }
+        } else {
+            let min = positions.iter().map(|pos| pos.x).min().unwrap();
+            let max = positions.iter().map(|pos| pos.x).max().unwrap();
+            for x in min..=max {
+                let y_positions: Vec<i32> = positions
+                    .iter()
+                    .filter(|pos| pos.x == x)
+                    .map(|pos| pos.y)
+                    .collect();
+                sum += calculate_sections(y_positions);
+            }
+        }
+    }
+
+    sum
+}
+
+fn calculate_sections(mut positions: Vec<i32>) -> usize {
+    if positions.is_empty() {
+        return 0;
+    }
+
+    positions.sort_unstable();
+
+    let mut sections = 1;
+    let mut curr = positions[0];
+    for next in positions.iter().skip(1) {
+        if *next > curr + 1 {
+            sections += 1;
+        }
+        curr = *next;
+    }
+    sections
+}
+
+type Position = Point<i32>;
+
+struct Region {
+    positions: Vec<Position>,
+    area: usize,
+    perimeter: usize,
+    sides: usize,
+}
+
+#[derive(Clone)]
+pub struct Map {
+    data: Grid<Field>,
+}
+
+impl Map {
+    fn get_pos(&self, pos: Position) -> Option<Field> {
+        self.data.get_signed(pos.x, pos.y).copied()
+    }
+
+    fn visit(&mut self, pos: Position) {
+        self.data[(pos.x as usize, pos.y as usize)] = Field::Visited;
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Field {
+    Visited,
+    Garden(char),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::answers::expected;
+
+    #[test]
+    fn test_small_a() {
+        let result = calculate_fencing_price("input_small_a.txt");
+        assert!(result.is_ok());
+        assert_eq!(
+            expected(1, "input_small_a.txt"),
+            result.unwrap().to_string()
+        )
+    }
+
+    #[test]
+    fn test_small_b() {
+        let result = calculate_fencing_price("input_small_b.txt");
+        assert!(result.is_ok());
+        assert_eq!(
+            expected(1, "input_small_b.txt"),
+            result.unwrap().to_string()
+        )
+    }
+
+    #[test]
+    fn test_small_c() {
+        let result = calculate_fencing_price("input_small_c.txt");
+        assert!(result.is_ok());
+        assert_eq!(
+            expected(1, "input_small_c.txt"),
+            result.unwrap().to_string()
+        )
+    }
+
+    #[test]
+    fn test_input_a() {
+        let result = calculate_fencing_price("input.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(1, "input.txt"), result.unwrap().to_string())
+    }
+
+    #[test]
+    fn test_small_ext_a() {
+        let result = calculate_fencing_price_ext("input_small_a.txt");
+        assert!(result.is_ok());
+        assert_eq!(
+            expected(2, "input_small_a.txt"),
+            result.unwrap().to_string()
+        )
+    }
+
+    #[test]
+    fn test_small_ext_c() {
+        let result = calculate_fencing_price_ext("input_small_c.txt");
+        assert!(result.is_ok());
+        assert_eq!(
+            expected(2, "input_small_c.txt"),
+            result.unwrap().to_string()
+        )
+    }
+
+    #[test]
+    fn test_input_b() {
+        let result = calculate_fencing_price_ext("input.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(2, "input.txt"), result.unwrap().to_string())
+    }
+}
@@ -0,0 +1,41 @@
+use crate::{get_regions_from_map, Day12, Field};
+use anyhow::Result;
+use aoc_common::render::{palette, Canvas, Cell, Render, DARK};
+
+impl Render for Day12 {
+    /// The plants of the garden, every region in a color of its own.
+    fn render(input: &Self::Input) -> Result<Canvas> {
+        let mut canvas = input.data.map(|field| match field {
+            Field::Garden(plant) => Cell::new(*plant, DARK),
+            Field::Visited => Cell::new('.', DARK),
+        });
+
+        for (index, region) in get_regions_from_map(input.clone()).iter().enumerate() {
+            for position in &region.positions {
+                canvas[(position.x as usize, position.y as usize)].color = palette(index);
+            }
+        }
+
+        Ok(canvas)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    #[test]
+    fn test_render() {
+        let map = parse("AAB\nBAB\n").unwrap();
+        let result = Day12::render(&map);
+        assert!(result.is_ok());
+
+        // the two regions of B plants differ in color
+        let canvas = result.unwrap();
+        assert_eq!("AAB\nBAB\n", canvas.to_string());
+        assert_eq!(canvas[(2, 0)].color, canvas[(2, 1)].color);
+        assert_ne!(canvas[(0, 1)].color, canvas[(2, 1)].color);
+        assert_ne!(canvas[(0, 0)].color, canvas[(2, 0)].color);
+    }
+}
@@ -0,0 +1,43 @@
+use crate::Day13;
+use aoc_common::generate::{Generated, Generator};
+use rand::{rngs::StdRng, Rng};
+
+impl Generator for Day13 {
+    const SIZE: usize = 320;
+
+    /// `size` claw machines, about a third of them can't win the prize.
+    ///
+    /// The prizes of the winnable machines are placed at a random number of
+    /// presses of both buttons. The others are moved one step to the right,
+    /// as long as that makes the number of presses a fraction.
+    fn generate(rng: &mut StdRng, size: usize) -> Generated {
+        let mut machines = Vec::new();
+        let mut tokens = 0;
+
+        while machines.len() < size {
+            let (ax, ay) = (rng.gen_range(10..100_i64), rng.gen_range(10..100_i64));
+            let (bx, by) = (rng.gen_range(10..100_i64), rng.gen_range(10..100_i64));
+            let determinant = ax * by - bx * ay;
+            if determinant == 0 {
+                continue;
+            }
+
+            let (a, b) = (rng.gen_range(0..=100), rng.gen_range(0..=100));
+            let (mut px, py) = (a * ax + b * bx, a * ay + b * by);
+
+            // one step to the right changes the presses by (by, -ay) / determinant
+            let still_winnable = by % determinant == 0 && ay % determinant == 0;
+            if still_winnable || rng.gen_bool(0.7) {
+                tokens += 3 * a + b;
+            } else {
+                px += 1;
+            }
+
+            machines.push(format!(
+                "Button A: X+{ax}, Y+{ay}\nButton B: X+{bx}, Y+{by}\nPrize: X={px}, Y={py}"
+            ));
+        }
+
+        Generated::new(machines.join("\n\n")).with_part1(tokens)
+    }
+}
@@ -0,0 +1,177 @@
+pub mod generate;
+
+use anyhow::Result;
+use aoc_common::{
+    error::{expect_prefix, parse_number},
+    ParseError, Solution,
+};
+use std::fmt::Display;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<ClawMachine>;
+
+    fn parse(data: &str) -> Result<Self::Input> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display> {
+        tokens(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display> {
+        tokens_with_offset(input)
+    }
+}
+
+pub fn calculate_tokens(filename: &str) -> Result<i64> {
+    tokens(&Day13::parse_file(filename)?)
+}
+
+pub fn calculate_tokens_with_offset(filename: &str) -> Result<i64> {
+    tokens_with_offset(&Day13::parse_file(filename)?)
+}
+
+fn tokens(claw_machines: &[ClawMachine]) -> Result<i64> {
+    let tokens = claw_machines
+        .iter()
+        .filter_map(|cm| cm.required_tokens())
+        .sum();
+
+    Ok(tokens)
+}
+
+fn tokens_with_offset(claw_machines: &[ClawMachine]) -> Result<i64> {
+    let mut claw_machines = claw_machines.to_vec();
+    for claw_machine in &mut claw_machines {
+        claw_machine.prize = Position(
+            claw_machine.prize.0 + 10000000000000,
+            claw_machine.prize.1 + 10000000000000,
+        );
+    }
+
+    let tokens = claw_machines
+        .iter()
+        .filter_map(|cm| cm.required_tokens())
+        .sum();
+
+    Ok(tokens)
+}
+
+pub fn parse(data: &str) -> Result<Vec<ClawMachine>> {
+    let lines: Vec<(usize, &str)> = data
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .collect();
+
+    let mut claw_machines = Vec::new();
+    for machine in lines.chunks(3) {
+        let &[(a_index, a), (b_index, b), (prize_index, prize)] = machine else {
+            let (index, _) = machine[machine.len() - 1];
+            return Err(ParseError::new(None, "a button B and a prize line")
+                .at(index + 2, 1)
+                .into());
+        };
+
+        claw_machines.push(ClawMachine {
+            a: parse_position(a_index, a, "Button A: X+", ", Y+")?,
+            b: parse_position(b_index, b, "Button B: X+", ", Y+")?,
+            prize: parse_position(prize_index, prize, "Prize: X=", ", Y=")?,
+        });
+    }
+
+    Ok(claw_machines)
+}
+
+// parses a line like `Button A: X+94, Y+34`, the `prefix` comes before X and
+// the `separator` before Y
+fn parse_position(
+    index: usize,
+    line: &str,
+    prefix: &str,
+    separator: &str,
+) -> Result<Position, ParseError> {
+    let values = expect_prefix(index, line, line, prefix)?;
+    let end = values.find(',').unwrap_or(values.len());
+    let (x, rest) = values.split_at(end);
+    let y = expect_prefix(index, line, rest, separator)?;
+
+    Ok(Position(
+        parse_number(index, line, x)?,
+        parse_number(index, line, y)?,
+    ))
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Position(i64, i64);
+
+#[derive(Clone)]
+pub struct ClawMachine {
+    a: Position,
+    b: Position,
+    prize: Position,
+}
+
+impl ClawMachine {
+    fn required_tokens(&self) -> Option<i64> {
+        let b = (self.prize.1 * self.a.0 - self.prize.0 * self.a.1)
+            / (self.a.0 * self.b.1 - self.b.0 * self.a.1);
+        let a = (self.prize.0 - b * self.b.0) / (self.a.0);
+
+        if a * self.a.0 + b * self.b.0 == self.prize.0
+            && a * self.a.1 + b * self.b.1 == self.prize.1
+        {
+            Some(a * 3 + b)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::answers::expected;
+
+    #[test]
+    fn test_invalid_machine() {
+        let result = parse("Button A: X+94, Y+34\nButton B: X+22, Y-67\nPrize: X=8400, Y=5400\n");
+        let err = result.err().unwrap().downcast::<ParseError>().unwrap();
+        assert_eq!(Some('-'), err.found());
+        assert_eq!((Some(2), Some(18)), (err.line(), err.column()));
+
+        let result = parse("Button A: X+94, Y+34\nButton B: X+22, Y+67\n");
+        let err = result.err().unwrap().downcast::<ParseError>().unwrap();
+        assert_eq!(Some(3), err.line());
+    }
+
+    #[test]
+    fn test_small_a() {
+        let result = calculate_tokens("input_small.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(1, "input_small.txt"), result.unwrap().to_string())
+    }
+
+    #[test]
+    fn test_input_a() {
+        let result = calculate_tokens("input.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(1, "input.txt"), result.unwrap().to_string())
+    }
+
+    #[test]
+    fn test_small_b() {
+        let result = calculate_tokens_with_offset("input_small.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(2, "input_small.txt"), result.unwrap().to_string())
+    }
+
+    #[test]
+    fn test_input_b() {
+        let result = calculate_tokens_with_offset("input.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(2, "input.txt"), result.unwrap().to_string())
+    }
+}
@@ -0,0 +1,118 @@
+use crate::{Day14, HEIGHT, WIDTH};
+use aoc_common::generate::{Generated, Generator};
+use rand::{rngs::StdRng, Rng};
+use std::cmp::Ordering;
+
+// the seconds the solutions simulate
+const SAFETY_SECONDS: i32 = 100;
+const TREE_SECONDS: i32 = 10000;
+
+impl Generator for Day14 {
+    const SIZE: usize = 500;
+
+    /// `size` robots scattered over the bathroom of the actual puzzle and a
+    /// framed Christmas tree at a random second.
+    fn generate(rng: &mut StdRng, size: usize) -> Generated {
+        let second = rng.gen_range(SAFETY_SECONDS + 1..TREE_SECONDS);
+        robots(rng, WIDTH, HEIGHT, size, Some(second))
+    }
+}
+
+/// `count` robots in random places, plus the robots of a Christmas tree in a
+/// frame that is only assembled after `tree` seconds.
+///
+/// The frame keeps the flood fill of the solution away from the tree, so
+/// its robots stay dry. Without a tree only the safety factor is planted.
+pub fn robots(
+    rng: &mut StdRng,
+    width: i32,
+    height: i32,
+    count: usize,
+    tree: Option<i32>,
+) -> Generated {
+    let mut robots: Vec<((i32, i32), (i32, i32))> = Vec::new();
+    let velocity = |rng: &mut StdRng| loop {
+        let velocity = (
+            rng.gen_range(1 - width..width),
+            rng.gen_range(1 - height..height),
+        );
+        if velocity != (0, 0) {
+            return velocity;
+        }
+    };
+
+    for _ in 0..count {
+        let position = (rng.gen_range(0..width), rng.gen_range(0..height));
+        robots.push((position, velocity(rng)));
+    }
+
+    if let Some(second) = tree {
+        for (x, y) in framed_tree(rng, width, height) {
+            let (vx, vy) = velocity(rng);
+            let position = (
+                (x - vx * second).rem_euclid(width),
+                (y - vy * second).rem_euclid(height),
+            );
+            robots.push((position, (vx, vy)));
+        }
+    }
+
+    let mut quadrants = [0; 4];
+    for &((x, y), (vx, vy)) in &robots {
+        let x = (x + vx * SAFETY_SECONDS).rem_euclid(width);
+        let y = (y + vy * SAFETY_SECONDS).rem_euclid(height);
+        match (x.cmp(&(width / 2)), y.cmp(&(height / 2))) {
+            (Ordering::Less, Ordering::Less) => quadrants[0] += 1,
+            (Ordering::Less, Ordering::Greater) => quadrants[1] += 1,
+            (Ordering::Greater, Ordering::Less) => quadrants[2] += 1,
+            (Ordering::Greater, Ordering::Greater) => quadrants[3] += 1,
+            _ => {}
+        }
+    }
+
+    let lines: Vec<String> = robots
+        .iter()
+        .map(|((x, y), (vx, vy))| format!("p={x},{y} v={vx},{vy}"))
+        .collect();
+    let generated =
+        Generated::new(lines.join("\n")).with_part1(quadrants.iter().product::<usize>());
+
+    match tree {
+        Some(second) => generated.with_part2(second),
+        None => generated,
+    }
+}
+
+/// The positions of a frame with a tree inside, somewhere in the bathroom.
+fn framed_tree(rng: &mut StdRng, width: i32, height: i32) -> Vec<(i32, i32)> {
+    let (frame_width, frame_height) = (width.min(31), height.min(33));
+    let left = rng.gen_range(0..=width - frame_width);
+    let top = rng.gen_range(0..=height - frame_height);
+    let (right, bottom) = (left + frame_width - 1, top + frame_height - 1);
+
+    let mut positions = Vec::new();
+    for x in left..=right {
+        positions.extend([(x, top), (x, bottom)]);
+    }
+    for y in top + 1..bottom {
+        positions.extend([(left, y), (right, y)]);
+    }
+
+    // the outline of a triangle that gets wider every other row and a trunk,
+    // a filled tree crowds the columns of the frame every 101 seconds so
+    // much that random robots get enclosed as well
+    let center = left + frame_width / 2;
+    let (crown, base) = (top + 2, bottom - 5);
+    for (row, y) in (crown..=base).enumerate() {
+        let half = (row as i32 / 2).min(frame_width / 2 - 2);
+        match y == base {
+            true => positions.extend((center - half..=center + half).map(|x| (x, y))),
+            false => positions.extend([(center - half, y), (center + half, y)]),
+        }
+    }
+    for y in base + 1..bottom - 1 {
+        positions.extend((center - 1..=center + 1).map(|x| (x, y)));
+    }
+
+    positions
+}
@@ -0,0 +1,362 @@
+pub mod generate;
+pub mod render;
+
+use anyhow::{bail, Result};
+use aoc_common::{
+    error::{expect_prefix, offset_in, parse_number},
+    record::Recorder,
+    ParseError, Solution,
+};
+use std::{
+    cmp::Ordering,
+    fmt::{Debug, Display},
+};
+use tracing::{debug, trace};
+
+// the size of the bathroom in the actual puzzle
+const WIDTH: i32 = 101;
+const HEIGHT: i32 = 103;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Vec<Robot>;
+
+    fn parse(data: &str) -> Result<Self::Input> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display> {
+        Ok(safety_factor(Map::new(input.clone(), WIDTH, HEIGHT)))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display> {
+        christmas_tree(
+            &mut Map::new(input.clone(), WIDTH, HEIGHT),
+            &mut Recorder::off(),
+        )
+    }
+}
+
+pub fn calculate_safety_factor(filename: &str, width: i32, height: i32) -> Result<usize> {
+    let robots = Day14::parse_file(filename)?;
+    Ok(safety_factor(Map::new(robots, width, height)))
+}
+
+pub fn find_christmas_tree(filename: &str, width: i32, height: i32) -> Result<usize> {
+    let robots = Day14::parse_file(filename)?;
+    christmas_tree(&mut Map::new(robots, width, height), &mut Recorder::off())
+}
+
+fn safety_factor(mut map: Map) -> usize {
+    for _ in 0..100 {
+        map.simulate();
+    }
+
+    map.safety_factor()
+}
+
+// simulates until the robots form the tree and leaves them there
+fn christmas_tree(map: &mut Map, recorder: &mut Recorder) -> Result<usize> {
+    recorder.step(|| map.canvas());
+    for i in 0..10000 {
+        map.simulate();
+        recorder.step(|| map.canvas());
+        let mut flood_map = FloodMap::new(map);
+        flood_map.fill();
+
+        let dry_robots = flood_map.dry_robots();
+        trace!(seconds = i + 1, dry_robots, "simulated");
+        if dry_robots > 5 {
+            debug!(seconds = i + 1, "found the christmas tree\n{map:?}");
+            recorder.finish(|| map.canvas());
+            return Ok(i + 1);
+        }
+    }
+    bail!("No tree found")
+}
+
+pub fn parse(data: &str) -> Result<Vec<Robot>> {
+    let robots = data
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| parse_robot(index, line))
+        .collect::<Result<_, _>>()?;
+
+    Ok(robots)
+}
+
+// parses a line like `p=0,4 v=3,-3`
+fn parse_robot(index: usize, line: &str) -> Result<Robot, ParseError> {
+    let values = expect_prefix(index, line, line, "p=")?;
+    let end = values.find(' ').unwrap_or(values.len());
+    let (position, rest) = values.split_at(end);
+    let velocity = expect_prefix(index, line, rest, " v=")?;
+
+    let (x, y) = split_pair(index, line, position)?;
+    let position = Position(
+        parse_coordinate(index, line, x, WIDTH)?,
+        parse_coordinate(index, line, y, HEIGHT)?,
+    );
+    let (x, y) = split_pair(index, line, velocity)?;
+    let velocity = Velocity(parse_number(index, line, x)?, parse_number(index, line, y)?);
+    Ok(Robot { position, velocity })
+}
+
+fn split_pair<'a>(
+    index: usize,
+    line: &str,
+    field: &'a str,
+) -> Result<(&'a str, &'a str), ParseError> {
+    field.split_once(',').ok_or_else(|| {
+        ParseError::in_line(index, line, offset_in(line, field) + field.len(), "','")
+    })
+}
+
+// the robots start inside the bathroom
+fn parse_coordinate(index: usize, line: &str, field: &str, size: i32) -> Result<i32, ParseError> {
+    let value = parse_number(index, line, field)?;
+    if !(0..size).contains(&value) {
+        let expected = format!("a coordinate from 0 to {}", size - 1);
+        return Err(ParseError::in_line(
+            index,
+            line,
+            offset_in(line, field),
+            expected,
+        ));
+    }
+    Ok(value)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Position(i32, i32);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Velocity(i32, i32);
+
+#[derive(Clone)]
+pub struct Robot {
+    position: Position,
+    velocity: Velocity,
+}
+
+struct Map {
+    robots: Vec<Robot>,
+    width: i32,
+    height: i32,
+}
+
+impl Debug for Map {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.canvas())
+    }
+}
+
+impl Map {
+    fn new(robots: Vec<Robot>, width: i32, height: i32) -> Map {
+        Map {
+            robots,
+            width,
+            height,
+        }
+    }
+
+    fn simulate(&mut self) {
+        for robot in &mut self.robots {
+            // the velocities may be larger than the bathroom
+            let x = (robot.position.0 + robot.velocity.0).rem_euclid(self.width);
+            let y = (robot.position.1 + robot.velocity.1).rem_euclid(self.height);
+            robot.position = Position(x, y);
+        }
+    }
+
+    fn safety_factor(&self) -> usize {
+        let mut quadrants = [0, 0, 0, 0];
+        for robot in &self.robots {
+            match (
+                robot.position.0.cmp(&(self.width / 2)),
+                robot.position.1.cmp(&(self.height / 2)),
+            ) {
+                (Ordering::Less, Ordering::Less) => {
+                    quadrants[0] += 1;
+                }
+                (Ordering::Less, Ordering::Greater) => {
+                    quadrants[1] += 1;
+                }
+                (Ordering::Greater, Ordering::Less) => {
+                    quadrants[2] += 1;
+                }
+                (Ordering::Greater, Ordering::Greater) => {
+                    quadrants[3] += 1;
+                }
+                (_, _) => {}
+            }
+        }
+
+        quadrants.iter().product()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FloodField {
+    Dry,
+    Filled,
+    DryRobot,
+}
+
+struct FloodMap {
+    map: Vec<Vec<FloodField>>,
+    width: i32,
+    height: i32,
+}
+
+impl FloodMap {
+    fn new(map: &Map) -> FloodMap {
+        // create dry flood map
+        let mut flood_map: Vec<Vec<FloodField>> = (0..map.height)
+            .map(|_| vec![FloodField::Dry; map.width as usize])
+            .collect();
+
+        // fill every robot with water
+        for robot in &map.robots {
+            flood_map[robot.position.1 as usize][robot.position.0 as usize] = FloodField::DryRobot;
+        }
+
+        FloodMap {
+            map: flood_map,
+            width: map.width,
+            height: map.height,
+        }
+    }
+
+    fn fill(&mut self) {
+        self.fill_from_pos(Position(0, 0));
+        self.fill_from_pos(Position(self.width - 1, 0));
+        self.fill_from_pos(Position(0, self.height - 1));
+        self.fill_from_pos(Position(self.width - 1, self.height - 1));
+    }
+
+    fn fill_from_pos(&mut self, pos: Position) {
+        // illegal position
+        if pos.0 < 0 || pos.1 < 0 || pos.0 >= self.width || pos.1 >= self.height {
+            return;
+        }
+
+        let field = &mut self.map[pos.1 as usize][pos.0 as usize];
+        if *field == FloodField::Filled {
+            return;
+        }
+
+        let was_robot = *field == FloodField::DryRobot;
+        *field = FloodField::Filled;
+
+        // only fill robot but don't continue
+        // so that only the outer robots will get wet
+        if was_robot {
+            return;
+        }
+
+        // fill neighbor fields
+        self.fill_from_pos(Position(pos.0 - 1, pos.1));
+        self.fill_from_pos(Position(pos.0 + 1, pos.1));
+        self.fill_from_pos(Position(pos.0, pos.1 - 1));
+        self.fill_from_pos(Position(pos.0, pos.1 + 1));
+    }
+
+    fn dry_robots(&self) -> usize {
+        let mut sum = 0;
+        for y in 0..self.height as usize {
+            for x in 0..self.width as usize {
+                if self.map[y][x] == FloodField::DryRobot {
+                    sum += 1;
+                }
+            }
+        }
+        sum
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::answers::expected;
+
+    #[test]
+    fn test_invalid_robot() {
+        let result = parse("p=0,4 v=3,-3\np=6,3 v=-1,x3\n");
+        let err = result.err().unwrap().downcast::<ParseError>().unwrap();
+        assert_eq!(Some('x'), err.found());
+        assert_eq!((Some(2), Some(12)), (err.line(), err.column()));
+
+        let result = parse("p=0,4 v=3,-3\np=6,3\n");
+        let err = result.err().unwrap().downcast::<ParseError>().unwrap();
+        assert_eq!((Some(2), None), (err.line(), err.found()));
+
+        let result = parse("p=0,4 v=3,-3\np=6,103 v=-1,3\n");
+        let err = result.err().unwrap().downcast::<ParseError>().unwrap();
+        assert_eq!((Some(2), Some(5)), (err.line(), err.column()));
+
+        let result = parse("p=-1,4 v=3,-3\n");
+        let err = result.err().unwrap().downcast::<ParseError>().unwrap();
+        assert_eq!(
+            (Some(1), Some(3), Some('-')),
+            (err.line(), err.column(), err.found())
+        );
+    }
+
+    #[test]
+    fn test_fast_robots() {
+        let robot = Robot {
+            position: Position(2, 4),
+            velocity: Velocity(300, -300),
+        };
+        let mut map = Map::new(vec![robot], 11, 7);
+        map.simulate();
+        assert_eq!(Position(5, 5), map.robots[0].position);
+        let _ = FloodMap::new(&map);
+    }
+
+    #[test]
+    fn test_small_a() {
+        let result = calculate_safety_factor("input_small.txt", 11, 7);
+        assert!(result.is_ok());
+        assert_eq!(12, result.unwrap())
+    }
+
+    #[test]
+    fn test_input_a() {
+        let result = calculate_safety_factor("input.txt", 101, 103);
+        assert!(result.is_ok());
+        assert_eq!(expected(1, "input.txt"), result.unwrap().to_string())
+    }
+
+    #[test]
+    fn test_flood_fill() {
+        let mut robots = Vec::new();
+        for y in 2..=6 {
+            for x in 2..=6 {
+                robots.push(Robot {
+                    position: Position(x, y),
+                    velocity: Velocity(0, 0),
+                });
+            }
+        }
+
+        let map = Map {
+            robots,
+            width: 10,
+            height: 10,
+        };
+        let mut flood_map = FloodMap::new(&map);
+        flood_map.fill();
+        assert_ne!(0, flood_map.dry_robots())
+    }
+
+    #[test]
+    fn test_input_b() {
+        let result = find_christmas_tree("input.txt", 101, 103);
+        assert!(result.is_ok());
+        assert_eq!(expected(2, "input.txt"), result.unwrap().to_string())
+    }
+}
@@ -0,0 +1,70 @@
+use crate::{christmas_tree, Day14, Map, HEIGHT, WIDTH};
+use anyhow::Result;
+use aoc_common::{
+    grid::Grid,
+    record::{Record, Recorder},
+    render::{Canvas, Cell, Render, DARK, GREEN},
+};
+
+impl Render for Day14 {
+    /// The robots in the second they show the christmas tree.
+    fn render(input: &Self::Input) -> Result<Canvas> {
+        let mut map = Map::new(input.clone(), WIDTH, HEIGHT);
+        christmas_tree(&mut map, &mut Recorder::off())?;
+        Ok(map.canvas())
+    }
+}
+
+impl Record for Day14 {
+    /// The robots every second until they show the christmas tree.
+    fn record(input: &Self::Input, recorder: &mut Recorder) -> Result<()> {
+        let mut map = Map::new(input.clone(), WIDTH, HEIGHT);
+        christmas_tree(&mut map, recorder)?;
+        Ok(())
+    }
+}
+
+impl Map {
+    pub(crate) fn canvas(&self) -> Canvas {
+        let mut canvas = Grid::filled(
+            self.width as usize,
+            self.height as usize,
+            Cell::new('.', DARK),
+        );
+        for robot in &self.robots {
+            let position = (robot.position.0 as usize, robot.position.1 as usize);
+            canvas[position] = Cell::new('O', GREEN);
+        }
+        canvas
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Position, Robot, Velocity};
+
+    #[test]
+    fn test_canvas() {
+        let robot = |x, y| Robot {
+            position: Position(x, y),
+            velocity: Velocity(0, 0),
+        };
+        let map = Map::new(vec![robot(0, 0), robot(2, 1), robot(2, 1)], 4, 2);
+        assert_eq!("O...\n..O.\n", format!("{map:?}"));
+        assert_eq!(GREEN, map.canvas()[(2, 1)].color);
+    }
+
+    #[test]
+    fn test_record() {
+        let robots = crate::parse(&std::fs::read_to_string("input.txt").unwrap()).unwrap();
+        let mut recorder = Recorder::new(1000);
+        assert!(Day14::record(&robots, &mut recorder).is_ok());
+
+        // the frames of the seconds 0, 1000, ... and the one of the tree
+        let seconds = recorder.steps() - 1;
+        assert_eq!(seconds / 1000 + 2, recorder.frames().len());
+        let tree = recorder.frames().last().unwrap();
+        assert_eq!(&Day14::render(&robots).unwrap(), tree);
+    }
+}
@@ -0,0 +1,38 @@
+use crate::Day15;
+use aoc_common::generate::{Generated, Generator};
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+
+impl Generator for Day15 {
+    const SIZE: usize = 50;
+
+    /// A walled warehouse of `size` by `size` with boxes and a few walls
+    /// inside, followed by eight movements per tile in lines of a thousand.
+    fn generate(rng: &mut StdRng, size: usize) -> Generated {
+        let size = size.max(3);
+        let mut map: Vec<Vec<char>> = (0..size)
+            .map(|y| {
+                (0..size)
+                    .map(|x| {
+                        if x == 0 || y == 0 || x == size - 1 || y == size - 1 {
+                            return '#';
+                        }
+                        match rng.gen_range(0..20) {
+                            0 => '#',
+                            1..=6 => 'O',
+                            _ => '.',
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+        map[rng.gen_range(1..size - 1)][rng.gen_range(1..size - 1)] = '@';
+
+        let movements: Vec<char> = (0..size * size * 8)
+            .map(|_| *['^', '>', 'v', '<'].choose(rng).unwrap())
+            .collect();
+
+        let map: Vec<String> = map.into_iter().map(String::from_iter).collect();
+        let movements: Vec<String> = movements.chunks(1000).map(String::from_iter).collect();
+        Generated::new(format!("{}\n\n{}", map.join("\n"), movements.join("\n")))
+    }
+}
@@ -0,0 +1,347 @@
+pub mod generate;
+pub mod render;
+
+use anyhow::{bail, ensure, Context, Result};
+use aoc_common::{grid::Grid, record::Recorder, Direction, ParseError, Point, Solution};
+use std::{
+    collections::VecDeque,
+    fmt::{Debug, Display},
+};
+use tracing::trace;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Warehouse;
+
+    fn parse(data: &str) -> Result<Self::Input> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display> {
+        gps_sum(input, false)
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display> {
+        gps_sum(input, true)
+    }
+}
+
+pub fn calculate_gps_sum(filename: &str) -> Result<usize> {
+    gps_sum(&Day15::parse_file(filename)?, false)
+}
+
+pub fn calculate_gps_sum_scaled_map(filename: &str) -> Result<usize> {
+    gps_sum(&Day15::parse_file(filename)?, true)
+}
+
+fn gps_sum(warehouse: &Warehouse, scaled: bool) -> Result<usize> {
+    let map = move_robot(warehouse, scaled, &mut Recorder::off())?;
+    Ok(map.get_box_coordinates())
+}
+
+// the warehouse after the robot made all its moves
+fn move_robot(warehouse: &Warehouse, scaled: bool, recorder: &mut Recorder) -> Result<Map> {
+    let mut map = Map::new(warehouse.map.clone(), scaled)?;
+    let mut robot = warehouse.movements.clone();
+    trace!("initial warehouse\n{map:?}");
+    recorder.step(|| map.canvas());
+
+    while let Some(direction) = robot.0.pop_front() {
+        map.robot_move(direction);
+        trace!(?direction, robot = %map.robot, "robot move");
+        recorder.step(|| map.canvas());
+    }
+    recorder.finish(|| map.canvas());
+
+    Ok(map)
+}
+
+pub fn parse(data: &str) -> Result<Warehouse> {
+    let index = data
+        .find("\n\n")
+        .context("No blank line between the map and the movements")?;
+    let map = data[..index].parse()?;
+    check_walls(&map)?;
+    ensure!(
+        map.find(&Entity::Robot).is_some(),
+        "No robot in the warehouse"
+    );
+
+    // the movements start after the map and the blank line
+    let first_line = data[..index].lines().count() + 1;
+    let mut movements = VecDeque::new();
+    for (i, line) in data[index + 2..].lines().enumerate() {
+        for (offset, c) in line.char_indices() {
+            if c.is_whitespace() {
+                continue;
+            }
+
+            let direction = Direction::from_arrow(c).ok_or_else(|| {
+                ParseError::in_line(first_line + i, line, offset, "'^', '>', 'v' or '<'")
+            })?;
+            movements.push_back(direction);
+        }
+    }
+
+    Ok(Warehouse {
+        map,
+        movements: RobotMovements(movements),
+    })
+}
+
+// the robot relies on the walls around the warehouse to never leave the map
+fn check_walls(map: &Grid<Entity>) -> Result<(), ParseError> {
+    let (width, height) = (map.width(), map.height());
+    for ((x, y), entity) in map.iter() {
+        let border = x == 0 || y == 0 || x == width - 1 || y == height - 1;
+        if border && *entity != Entity::Wall {
+            return Err(
+                ParseError::new(Some((*entity).into()), "'#' around the warehouse")
+                    .at(y + 1, x + 1),
+            );
+        }
+    }
+
+    Ok(())
+}
+
+pub struct Warehouse {
+    map: Grid<Entity>,
+    movements: RobotMovements,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Entity {
+    Empty,
+    Wall,
+    Box,
+    LeftBox,
+    RightBox,
+    Robot,
+}
+
+impl TryFrom<char> for Entity {
+    type Error = ParseError;
+
+    fn try_from(c: char) -> Result<Self, ParseError> {
+        match c {
+            '#' => Ok(Entity::Wall),
+            '.' => Ok(Entity::Empty),
+            'O' => Ok(Entity::Box),
+            '@' => Ok(Entity::Robot),
+            _ => Err(ParseError::new(Some(c), "'#', '.', 'O' or '@'")),
+        }
+    }
+}
+
+impl From<Entity> for char {
+    fn from(entity: Entity) -> Self {
+        match entity {
+            Entity::Box => 'O',
+            Entity::Wall => '#',
+            Entity::LeftBox => '[',
+            Entity::RightBox => ']',
+            Entity::Robot => '@',
+            Entity::Empty => '.',
+        }
+    }
+}
+
+type Position = Point<usize>;
+
+struct Map {
+    data: Grid<Entity>,
+    robot: Position,
+}
+
+impl Map {
+    fn new(mut data: Grid<Entity>, scaled: bool) -> Result<Map> {
+        if scaled {
+            let scaled_map = data
+                .cells()
+                .map(|entity| match entity {
+                    Entity::Box => Ok([Entity::LeftBox, Entity::RightBox]),
+                    Entity::Empty => Ok([Entity::Empty, Entity::Empty]),
+                    Entity::Wall => Ok([Entity::Wall, Entity::Wall]),
+                    Entity::Robot => Ok([Entity::Robot, Entity::Empty]),
+                    Entity::LeftBox | Entity::RightBox => bail!("The warehouse is already scaled"),
+                })
+                .collect::<Result<Vec<_>>>()?;
+            let scaled_map = scaled_map.into_iter().flatten().collect();
+            data = Grid::new(2 * data.width(), data.height(), scaled_map)?;
+        }
+
+        let robot = data
+            .find(&Entity::Robot)
+            .context("No robot in the warehouse")?;
+
+        Ok(Map {
+            data,
+            robot: robot.into(),
+        })
+    }
+
+    // the warehouse is surrounded by walls, which `check_walls` ensures while
+    // parsing, so nothing ever moves off the map
+    fn go_to(&self, position: Position, direction: Direction) -> Position {
+        position
+            .step_within(direction, self.data.width(), self.data.height())
+            .expect("Moved out of the warehouse")
+    }
+
+    fn robot_move(&mut self, direction: Direction) {
+        if self.can_move(self.robot, direction) {
+            self.move_to(self.robot, direction, false);
+            self.robot = self.go_to(self.robot, direction);
+        }
+    }
+
+    fn can_move(&self, position: Position, direction: Direction) -> bool {
+        let entity = self.data[position];
+        match entity {
+            Entity::Empty => true,
+            Entity::Wall => false,
+            Entity::LeftBox => {
+                self.can_move_big_box(position, self.go_to(position, Direction::Right), direction)
+            }
+            Entity::RightBox => {
+                self.can_move_big_box(self.go_to(position, Direction::Left), position, direction)
+            }
+            _ => self.can_move(self.go_to(position, direction), direction),
+        }
+    }
+
+    fn can_move_big_box(&self, left: Position, right: Position, direction: Direction) -> bool {
+        match direction {
+            Direction::Left => self.can_move(self.go_to(left, Direction::Left), Direction::Left),
+            Direction::Right => {
+                self.can_move(self.go_to(right, Direction::Right), Direction::Right)
+            }
+            Direction::Up => {
+                self.can_move(self.go_to(left, Direction::Up), Direction::Up)
+                    && self.can_move(self.go_to(right, Direction::Up), Direction::Up)
+            }
+            Direction::Down => {
+                self.can_move(self.go_to(left, Direction::Down), Direction::Down)
+                    && self.can_move(self.go_to(right, Direction::Down), Direction::Down)
+            }
+        }
+    }
+
+    fn move_to(&mut self, position: Position, direction: Direction, is_big_box: bool) {
+        let entity = self.data[position];
+        let new_position = self.go_to(position, direction);
+        let new_entity = self.data[new_position];
+
+        // if new position is a box then first move the box in the direction
+        if new_entity == Entity::Box
+            || new_entity == Entity::LeftBox
+            || new_entity == Entity::RightBox
+        {
+            self.move_to(new_position, direction, false);
+        }
+
+        if !is_big_box && (direction == Direction::Down || direction == Direction::Up) {
+            if entity == Entity::LeftBox {
+                self.move_to(self.go_to(position, Direction::Right), direction, true);
+            }
+            if entity == Entity::RightBox {
+                self.move_to(self.go_to(position, Direction::Left), direction, true);
+            }
+        }
+
+        self.data[position] = Entity::Empty;
+        self.data[new_position] = entity;
+    }
+
+    fn get_box_coordinates(&self) -> usize {
+        self.data
+            .iter()
+            .filter(|(_, e)| **e == Entity::Box || **e == Entity::LeftBox)
+            .map(|(position, _)| self.get_gps(position.into()))
+            .sum()
+    }
+
+    fn get_gps(&self, position: Position) -> usize {
+        position.x + 100 * position.y
+    }
+}
+
+impl Debug for Map {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.canvas())
+    }
+}
+
+#[derive(Clone)]
+struct RobotMovements(VecDeque<Direction>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{answers::expected, solution::solve};
+
+    #[test]
+    fn test_invalid_warehouse() {
+        let result = parse("#####\n#.@.#\n#.O..\n#####\n\n<^\n");
+        let err = result.err().unwrap().downcast::<ParseError>().unwrap();
+        assert_eq!(Some('.'), err.found());
+        assert_eq!((Some(3), Some(5)), (err.line(), err.column()));
+
+        assert!(parse("#####\n#...#\n#.O.#\n#####\n\n<^\n").is_err());
+    }
+
+    #[test]
+    fn test_scale_twice() {
+        let data = Grid::new(3, 1, vec![Entity::Robot, Entity::LeftBox, Entity::RightBox]).unwrap();
+        assert!(Map::new(data.clone(), false).is_ok());
+        assert!(Map::new(data, true).is_err());
+    }
+
+    #[test]
+    fn test_crlf_input() {
+        let data = std::fs::read_to_string("input_smaller.txt").unwrap();
+        let result = solve::<Day15>(1, &data.replace('\n', "\r\n"));
+        assert!(result.is_ok());
+        assert_eq!(expected(1, "input_smaller.txt"), result.unwrap())
+    }
+
+    #[test]
+    fn test_smaller_a() {
+        let result = calculate_gps_sum("input_smaller.txt");
+        assert!(result.is_ok());
+        assert_eq!(
+            expected(1, "input_smaller.txt"),
+            result.unwrap().to_string()
+        )
+    }
+
+    #[test]
+    fn test_small_a() {
+        let result = calculate_gps_sum("input_small.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(1, "input_small.txt"), result.unwrap().to_string())
+    }
+
+    #[test]
+    fn test_input_a() {
+        let result = calculate_gps_sum("input.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(1, "input.txt"), result.unwrap().to_string())
+    }
+
+    #[test]
+    fn test_small_b() {
+        let result = calculate_gps_sum_scaled_map("input_small.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(2, "input_small.txt"), result.unwrap().to_string())
+    }
+
+    #[test]
+    fn test_input_b() {
+        let result = calculate_gps_sum_scaled_map("input.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(2, "input.txt"), result.unwrap().to_string())
+    }
+}
@@ -0,0 +1,69 @@
+use crate::{move_robot, Day15, Entity, Map};
+use anyhow::Result;
+use aoc_common::{
+    record::{Record, Recorder},
+    render::{Canvas, Cell, Render, DARK, GREY, RED, YELLOW},
+};
+
+impl Render for Day15 {
+    /// The scaled warehouse after the robot made all its moves.
+    fn render(input: &Self::Input) -> Result<Canvas> {
+        Ok(move_robot(input, true, &mut Recorder::off())?.canvas())
+    }
+}
+
+impl Record for Day15 {
+    /// The scaled warehouse after every move of the robot.
+    fn record(input: &Self::Input, recorder: &mut Recorder) -> Result<()> {
+        move_robot(input, true, recorder)?;
+        Ok(())
+    }
+}
+
+impl Map {
+    pub(crate) fn canvas(&self) -> Canvas {
+        self.data.map(|&entity| {
+            let color = match entity {
+                Entity::Empty => DARK,
+                Entity::Wall => GREY,
+                Entity::Box | Entity::LeftBox | Entity::RightBox => YELLOW,
+                Entity::Robot => RED,
+            };
+            Cell::new(entity.into(), color)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    #[test]
+    fn test_render() {
+        let warehouse = parse("#####\n#@O.#\n#####\n\n>>\n").unwrap();
+        let result = Day15::render(&warehouse);
+        assert!(result.is_ok());
+
+        let canvas = result.unwrap();
+        assert_eq!("##########\n##..@[].##\n##########\n", canvas.to_string());
+        assert_eq!(RED, canvas[(4, 1)].color);
+    }
+
+    #[test]
+    fn test_record() {
+        let warehouse = parse("#####\n#@O.#\n#####\n\n>>\n").unwrap();
+        let mut recorder = Recorder::new(1);
+        assert!(Day15::record(&warehouse, &mut recorder).is_ok());
+
+        let frames: Vec<String> = recorder.frames().iter().map(Canvas::to_string).collect();
+        assert_eq!(
+            vec![
+                "##########\n##@.[]..##\n##########\n",
+                "##########\n##.@[]..##\n##########\n",
+                "##########\n##..@[].##\n##########\n",
+            ],
+            frames
+        );
+    }
+}
@@ -0,0 +1,43 @@
+use crate::Day16;
+use aoc_common::{
+    generate::{maze, render_maze, shortest_path, Generated, Generator},
+    Direction, Point,
+};
+use rand::rngs::StdRng;
+
+impl Generator for Day16 {
+    const SIZE: usize = 141;
+
+    /// A perfect maze of `size` by `size` from the lower left to the upper
+    /// right corner, like the actual puzzle.
+    ///
+    /// There is only a single path from the start to the end, so it is the
+    /// best path and its score and length are planted.
+    fn generate(rng: &mut StdRng, size: usize) -> Generated {
+        let maze = maze(rng, size, size);
+        let start = (1, maze.height() - 2);
+        let end = (maze.width() - 2, 1);
+        let path = shortest_path(&maze, start, end).expect("A perfect maze connects all cells");
+
+        // the reindeer starts facing east and turns whenever the path does
+        let mut direction = Direction::Right;
+        let mut score = 0;
+        for step in path.windows(2) {
+            let (from, to) = (Point::from(step[0]), Point::from(step[1]));
+            let next = Direction::ALL
+                .into_iter()
+                .find(|&d| from.step_within(d, maze.width(), maze.height()) == Some(to))
+                .expect("The path only takes single steps");
+            score += match next {
+                _ if next == direction => 1,
+                _ if next == direction.reverse() => 2001,
+                _ => 1001,
+            };
+            direction = next;
+        }
+
+        Generated::new(render_maze(&maze, start, end))
+            .with_part1(score)
+            .with_part2(path.len())
+    }
+}
@@ -0,0 +1,197 @@
+pub mod generate;
+pub mod render;
+
+use anyhow::{Context, Result};
+use aoc_common::{
+    grid::Grid,
+    pathfinding::{dijkstra, Paths},
+    Direction, ParseError, Point, Solution,
+};
+use std::{collections::HashSet, fmt::Display};
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Map;
+
+    fn parse(data: &str) -> Result<Self::Input> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display> {
+        lowest_score(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display> {
+        best_path_tiles(input)
+    }
+}
+
+pub fn get_lowest_score(filename: &str) -> Result<usize> {
+    lowest_score(&Day16::parse_file(filename)?)
+}
+
+pub fn count_best_path_tiles(filename: &str) -> Result<usize> {
+    best_path_tiles(&Day16::parse_file(filename)?)
+}
+
+fn lowest_score(map: &Map) -> Result<usize> {
+    let (paths, ends) = map.search()?;
+    cheapest(&paths, &ends)
+}
+
+fn best_path_tiles(map: &Map) -> Result<usize> {
+    Ok(best_tiles(map)?.len())
+}
+
+// the tiles on any of the cheapest paths
+fn best_tiles(map: &Map) -> Result<HashSet<Position>> {
+    let (paths, ends) = map.search()?;
+    let score = cheapest(&paths, &ends)?;
+    let best_ends = ends
+        .into_iter()
+        .filter(|end| paths.distance(end) == Some(score));
+
+    let tiles = paths
+        .on_paths_to(best_ends)
+        .into_iter()
+        .map(|(position, _)| position)
+        .collect();
+    Ok(tiles)
+}
+
+// the reindeer may face any direction at the end
+fn cheapest(paths: &Paths<State>, ends: &[State]) -> Result<usize> {
+    ends.iter()
+        .filter_map(|end| paths.distance(end))
+        .min()
+        .context("The end can't be reached")
+}
+
+pub fn parse(file: &str) -> Result<Map> {
+    let data = file.parse()?;
+
+    Ok(Map { data })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Field {
+    Empty,
+    Wall,
+    Start,
+    End,
+}
+
+impl TryFrom<char> for Field {
+    type Error = ParseError;
+
+    fn try_from(c: char) -> Result<Self, ParseError> {
+        match c {
+            '#' => Ok(Field::Wall),
+            '.' => Ok(Field::Empty),
+            'S' => Ok(Field::Start),
+            'E' => Ok(Field::End),
+            _ => Err(ParseError::new(Some(c), "'#', '.', 'S' or 'E'")),
+        }
+    }
+}
+
+pub struct Map {
+    data: Grid<Field>,
+}
+
+type Position = Point<usize>;
+
+// the reindeer is at a tile facing a direction
+type State = (Position, Direction);
+
+impl Map {
+    // the cheapest paths from the start, facing east, to every state
+    // together with the states at the end tile
+    fn search(&self) -> Result<(Paths<State>, [State; 4])> {
+        let start = self.data.find(&Field::Start).context("No start field")?;
+        let end = self.data.find(&Field::End).context("No end field")?;
+
+        let paths = dijkstra([(start.into(), Direction::Right)], |&state| {
+            self.successors(state)
+        });
+        let ends = Direction::ALL.map(|direction| (end.into(), direction));
+
+        Ok((paths, ends))
+    }
+
+    // turning costs 1000 points, moving forward a single point
+    fn successors(&self, (position, direction): State) -> Vec<(State, usize)> {
+        let mut successors = vec![
+            ((position, direction.turn_left()), 1000),
+            ((position, direction.turn_right()), 1000),
+        ];
+
+        let next = position
+            .checked_add_signed(direction.into())
+            .filter(|&next| {
+                self.data
+                    .get(next)
+                    .is_some_and(|&field| field != Field::Wall)
+            });
+        if let Some(next) = next {
+            successors.push(((next, direction), 1));
+        }
+
+        successors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::answers::expected;
+
+    #[test]
+    fn test_small_a() {
+        let result = get_lowest_score("input_small.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(1, "input_small.txt"), result.unwrap().to_string())
+    }
+
+    #[test]
+    fn test_small_2_a() {
+        let result = get_lowest_score("input_small_2.txt");
+        assert!(result.is_ok());
+        assert_eq!(
+            expected(1, "input_small_2.txt"),
+            result.unwrap().to_string()
+        )
+    }
+
+    #[test]
+    fn test_input_a() {
+        let result = get_lowest_score("input.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(1, "input.txt"), result.unwrap().to_string())
+    }
+
+    #[test]
+    fn test_small_b() {
+        let result = count_best_path_tiles("input_small.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(2, "input_small.txt"), result.unwrap().to_string())
+    }
+
+    #[test]
+    fn test_small_2_b() {
+        let result = count_best_path_tiles("input_small_2.txt");
+        assert!(result.is_ok());
+        assert_eq!(
+            expected(2, "input_small_2.txt"),
+            result.unwrap().to_string()
+        )
+    }
+
+    #[test]
+    fn test_input_b() {
+        let result = count_best_path_tiles("input.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(2, "input.txt"), result.unwrap().to_string())
+    }
+}
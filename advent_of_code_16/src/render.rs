@@ -0,0 +1,40 @@
+use crate::{best_tiles, Day16, Field};
+use anyhow::Result;
+use aoc_common::render::{Canvas, Cell, Render, DARK, GREEN, GREY, RED};
+
+impl Render for Day16 {
+    /// The maze with the tiles on any of the best paths.
+    fn render(input: &Self::Input) -> Result<Canvas> {
+        let mut canvas = input.data.map(|field| match field {
+            Field::Empty => Cell::new('.', DARK),
+            Field::Wall => Cell::new('#', GREY),
+            Field::Start => Cell::new('S', RED),
+            Field::End => Cell::new('E', RED),
+        });
+
+        for tile in best_tiles(input)? {
+            if input.data[tile] == Field::Empty {
+                canvas[tile] = Cell::new('O', GREEN);
+            }
+        }
+
+        Ok(canvas)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    #[test]
+    fn test_render() {
+        let map = parse("#####\n#..E#\n#.#.#\n#S..#\n#####\n").unwrap();
+        let result = Day16::render(&map);
+        assert!(result.is_ok());
+        assert_eq!(
+            "#####\n#..E#\n#.#O#\n#SOO#\n#####\n",
+            result.unwrap().to_string()
+        );
+    }
+}
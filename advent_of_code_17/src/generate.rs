@@ -0,0 +1,77 @@
+use crate::Day17;
+use aoc_common::generate::{Generated, Generator};
+use rand::{rngs::StdRng, Rng};
+
+impl Generator for Day17 {
+    const SIZE: usize = 16;
+
+    /// A program like the ones of the actual puzzle and a register A that
+    /// makes it print `size` values.
+    ///
+    /// Every program has the shape `2,4,1,x,7,5,1,y,4,z,0,3,5,5,3,0`, a loop
+    /// that prints a value for each octal digit of A. Only constants with a
+    /// register A that makes the program print itself are used.
+    fn generate(rng: &mut StdRng, size: usize) -> Generated {
+        loop {
+            let (xor1, xor2, ignored) = (
+                rng.gen_range(0..8),
+                rng.gen_range(0..8),
+                rng.gen_range(0..8),
+            );
+            let program = [2, 4, 1, xor1, 7, 5, 1, xor2, 4, ignored, 0, 3, 5, 5, 3, 0];
+            let Some(replicating) = replicating_register(&program, xor1, xor2, 0, 0) else {
+                continue;
+            };
+
+            let digits = size.clamp(1, 21) as u32;
+            let register = rng.gen_range(8_u64.pow(digits - 1)..8_u64.pow(digits));
+            let output: Vec<String> = run(register, xor1, xor2)
+                .iter()
+                .map(u64::to_string)
+                .collect();
+            let program: Vec<String> = program.iter().map(u64::to_string).collect();
+
+            let input = format!(
+                "Register A: {register}\nRegister B: 0\nRegister C: 0\n\nProgram: {}",
+                program.join(",")
+            );
+            return Generated::new(input)
+                .with_part1(output.join(","))
+                .with_part2(replicating);
+        }
+    }
+}
+
+/// The output of the program, every loop computes a value from the lowest
+/// octal digit of A and the bits above it, then drops the digit.
+fn run(mut register: u64, xor1: u64, xor2: u64) -> Vec<u64> {
+    let mut output = Vec::new();
+    loop {
+        let b = (register % 8) ^ xor1;
+        output.push((b ^ xor2 ^ (register >> b)) % 8);
+        register >>= 3;
+        if register == 0 {
+            return output;
+        }
+    }
+}
+
+/// The lowest register A that prints `program`, its octal digits are chosen
+/// from the highest one, which prints the last value.
+fn replicating_register(
+    program: &[u64],
+    xor1: u64,
+    xor2: u64,
+    register: u64,
+    depth: usize,
+) -> Option<u64> {
+    if depth == program.len() {
+        return Some(register);
+    }
+
+    (0..8)
+        .map(|digit| register * 8 + digit)
+        .filter(|&candidate| candidate != 0)
+        .filter(|&candidate| run(candidate, xor1, xor2) == program[program.len() - depth - 1..])
+        .find_map(|candidate| replicating_register(program, xor1, xor2, candidate, depth + 1))
+}
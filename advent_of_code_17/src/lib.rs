@@ -0,0 +1,370 @@
+pub mod generate;
+
+use anyhow::{bail, Context, Result};
+use aoc_common::{error::parse_number, ParseError, Solution};
+use itertools::Itertools;
+use std::fmt::Display;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Program;
+
+    fn parse(data: &str) -> Result<Self::Input> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display> {
+        interpret(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display> {
+        self_replicating_register(input)
+    }
+}
+
+pub fn interpret_program(filename: &str) -> Result<String> {
+    interpret(&Day17::parse_file(filename)?)
+}
+
+pub fn find_self_replicating_register(filename: &str) -> Result<u64> {
+    self_replicating_register(&Day17::parse_file(filename)?)
+}
+
+fn interpret(program: &Program) -> Result<String> {
+    let mut program = program.clone();
+    program.run();
+
+    Ok(program.output())
+}
+
+fn self_replicating_register(program: &Program) -> Result<u64> {
+    let code = program.code();
+
+    find_register_digits(program, &code, 0, 0).context("No register value found")
+}
+
+// the program consumes register A three bits per output value, so the last
+// output value only depends on the highest octal digit of A. Search the digits
+// from the highest to the lowest and only follow candidates whose output
+// already matches the tail of the program.
+fn find_register_digits(
+    program: &Program,
+    code: &[u64],
+    register: u64,
+    depth: usize,
+) -> Option<u64> {
+    if depth == code.len() {
+        return Some(register);
+    }
+
+    for digit in 0..8 {
+        let candidate = register * 8 + digit;
+        if candidate == 0 {
+            continue;
+        }
+
+        // every candidate runs with the original registers B and C
+        let mut run = program.with_register_a(candidate);
+        run.run();
+
+        if run.output == code[code.len() - depth - 1..] {
+            if let Some(result) = find_register_digits(program, code, candidate, depth + 1) {
+                return Some(result);
+            }
+        }
+    }
+
+    None
+}
+
+pub fn parse(data: &str) -> Result<Program> {
+    let lines: Vec<&str> = data.lines().collect();
+    if lines.len() != 5 {
+        bail!("Invalid number of lines");
+    }
+
+    let registers = Registers {
+        a: get_register_value(0, lines[0])?,
+        b: get_register_value(1, lines[1])?,
+        c: get_register_value(2, lines[2])?,
+    };
+    let instructions = get_instructions(4, lines[4])?;
+
+    Ok(Program::new(registers, instructions))
+}
+
+fn get_register_value(index: usize, line: &str) -> Result<u64, ParseError> {
+    let (_, register) = line
+        .split_once(':')
+        .ok_or_else(|| ParseError::in_line(index, line, line.len(), "':'"))?;
+    parse_number(index, line, register.trim())
+}
+
+fn get_instructions(index: usize, line: &str) -> Result<Vec<Instruction>, ParseError> {
+    let (_, values) = line
+        .split_once(':')
+        .ok_or_else(|| ParseError::in_line(index, line, line.len(), "':'"))?;
+    let mut values = values.trim().split(',');
+
+    let mut instructions = Vec::new();
+    while let Some(opcode_field) = values.next() {
+        let operand_field = values
+            .next()
+            .ok_or_else(|| ParseError::in_line(index, line, line.len(), "an operand"))?;
+        let opcode = parse_number(index, line, opcode_field)?;
+        let operand = parse_number(index, line, operand_field)?;
+
+        let instruction = Instruction::new(opcode, operand).ok_or_else(|| {
+            ParseError::new(opcode_field.chars().next(), "an opcode from 0 to 7").at_field(
+                index,
+                line,
+                opcode_field,
+            )
+        })?;
+        let max_operand = instruction.max_operand();
+        if operand > max_operand {
+            let expected = format!("an operand from 0 to {max_operand}");
+            return Err(
+                ParseError::new(operand_field.chars().next(), expected).at_field(
+                    index,
+                    line,
+                    operand_field,
+                ),
+            );
+        }
+        instructions.push(instruction);
+    }
+
+    Ok(instructions)
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Registers {
+    a: u64,
+    b: u64,
+    c: u64,
+}
+
+#[derive(Clone)]
+pub struct Program {
+    registers: Registers,
+    instructions: Vec<Instruction>,
+    index: usize,
+    output: Vec<u64>,
+}
+
+impl Program {
+    fn new(registers: Registers, instructions: Vec<Instruction>) -> Self {
+        Program {
+            registers,
+            instructions,
+            index: 0,
+            output: Vec::new(),
+        }
+    }
+
+    fn with_register_a(&self, a: u64) -> Program {
+        let registers = Registers {
+            a,
+            ..self.registers
+        };
+        Program::new(registers, self.instructions.clone())
+    }
+
+    fn code(&self) -> Vec<u64> {
+        self.instructions
+            .iter()
+            .flat_map(|instruction| {
+                let (opcode, operand) = instruction.code();
+                [opcode as u64, operand as u64]
+            })
+            .collect()
+    }
+
+    fn output(&self) -> String {
+        self.output.iter().map(|v| v.to_string()).join(",")
+    }
+
+    fn run(&mut self) {
+        while self.index < self.instructions.len() {
+            let instruction = self.instructions[self.index];
+            match instruction {
+                Instruction::Adv(denominator) => {
+                    let operand = self.combo_operand(denominator);
+                    let divison = self.registers.a / (2_u64.pow(operand as u32));
+                    self.registers.a = divison;
+                }
+                Instruction::Bxl(operand) => {
+                    let result = self.registers.b ^ operand as u64;
+                    self.registers.b = result;
+                }
+                Instruction::Bst(operand) => {
+                    self.registers.b = self.combo_operand(operand) % 8;
+                }
+                Instruction::Jnz(operand) => {
+                    if self.registers.a != 0 {
+                        self.index = operand as usize;
+                        continue;
+                    }
+                }
+                Instruction::Bxc(_) => {
+                    let result = self.registers.b ^ self.registers.c;
+                    self.registers.b = result;
+                }
+                Instruction::Out(operand) => {
+                    let value = self.combo_operand(operand) % 8;
+                    self.output.push(value);
+                }
+                Instruction::Bdv(denominator) => {
+                    let operand = self.combo_operand(denominator);
+                    let divison = self.registers.a / (2_u64.pow(operand as u32));
+                    self.registers.b = divison;
+                }
+                Instruction::Cdv(denominator) => {
+                    let operand = self.combo_operand(denominator);
+                    let divison = self.registers.a / (2_u64.pow(operand as u32));
+                    self.registers.c = divison;
+                }
+            }
+
+            self.index += 1;
+        }
+    }
+
+    fn combo_operand(&self, operand: u8) -> u64 {
+        match operand {
+            0..=3 => operand as u64,
+            4 => self.registers.a,
+            5 => self.registers.b,
+            6 => self.registers.c,
+            _ => unreachable!("combo operands are checked while parsing"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Instruction {
+    Adv(u8),
+    Bxl(u8),
+    Bst(u8),
+    Jnz(u8),
+    Bxc(u8),
+    Out(u8),
+    Bdv(u8),
+    Cdv(u8),
+}
+
+impl Instruction {
+    /// `None` if there is no instruction with the `opcode`.
+    fn new(opcode: u8, operand: u8) -> Option<Instruction> {
+        match opcode {
+            0 => Some(Instruction::Adv(operand)),
+            1 => Some(Instruction::Bxl(operand)),
+            2 => Some(Instruction::Bst(operand)),
+            3 => Some(Instruction::Jnz(operand)),
+            4 => Some(Instruction::Bxc(operand)),
+            5 => Some(Instruction::Out(operand)),
+            6 => Some(Instruction::Bdv(operand)),
+            7 => Some(Instruction::Cdv(operand)),
+            _ => None,
+        }
+    }
+
+    // operands are three bits, the combo operand 7 is reserved
+    fn max_operand(&self) -> u8 {
+        match self {
+            Instruction::Adv(_)
+            | Instruction::Bst(_)
+            | Instruction::Out(_)
+            | Instruction::Bdv(_)
+            | Instruction::Cdv(_) => 6,
+            _ => 7,
+        }
+    }
+
+    fn code(&self) -> (u8, u8) {
+        match *self {
+            Instruction::Adv(operand) => (0, operand),
+            Instruction::Bxl(operand) => (1, operand),
+            Instruction::Bst(operand) => (2, operand),
+            Instruction::Jnz(operand) => (3, operand),
+            Instruction::Bxc(operand) => (4, operand),
+            Instruction::Out(operand) => (5, operand),
+            Instruction::Bdv(operand) => (6, operand),
+            Instruction::Cdv(operand) => (7, operand),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::answers::expected;
+
+    #[test]
+    fn test_invalid_program() {
+        let registers = "Register A: 729\nRegister B: 0\nRegister C: 0\n\n";
+        let error = |program: &str| {
+            let result = parse(&format!("{registers}{program}\n"));
+            let err = result.err().unwrap().downcast::<ParseError>().unwrap();
+            (err.line(), err.column(), err.found())
+        };
+
+        assert_eq!((Some(5), Some(14), Some('8')), error("Program: 0,1,8,4"));
+        assert_eq!((Some(5), Some(16), Some('7')), error("Program: 0,1,5,7"));
+        assert_eq!((Some(5), Some(15), None), error("Program: 0,1,5"));
+    }
+
+    #[test]
+    fn test_register_digits_keep_registers() {
+        // prints B before changing it, so every run has to start from the
+        // initial B instead of the B the previous candidate ended with
+        let program = |b: u64| {
+            let registers = format!("Register A: 0\nRegister B: {b}\nRegister C: 6\n\n");
+            parse(&format!("{registers}Program: 5,5,2,4,0,3,3,0\n")).unwrap()
+        };
+
+        let result = find_register_digits(&program(5), &[5, 5, 5], 0, 0);
+        assert_eq!(Some(109), result);
+        let mut program = program(5).with_register_a(109);
+        program.run();
+        assert_eq!("5,5,5", program.output());
+
+        // no A prints 1,2 for this B
+        let program =
+            parse("Register A: 0\nRegister B: 2\nRegister C: 3\n\nProgram: 5,5,1,3,0,3,3,0\n");
+        assert_eq!(None, find_register_digits(&program.unwrap(), &[1, 2], 0, 0));
+    }
+
+    #[test]
+    fn test_small_a() {
+        let result = interpret_program("input_small.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(1, "input_small.txt"), result.unwrap().to_string())
+    }
+
+    #[test]
+    fn test_input_a() {
+        let result = interpret_program("input.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(1, "input.txt"), result.unwrap().to_string())
+    }
+
+    #[test]
+    fn test_small_b() {
+        let result = find_self_replicating_register("input_small_b.txt");
+        assert!(result.is_ok());
+        assert_eq!(
+            expected(2, "input_small_b.txt"),
+            result.unwrap().to_string()
+        )
+    }
+
+    #[test]
+    fn test_input_b() {
+        let result = find_self_replicating_register("input.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(2, "input.txt"), result.unwrap().to_string())
+    }
+}
@@ -0,0 +1,65 @@
+use crate::{Day18, DIMENSION, FALLEN_BYTES};
+use aoc_common::{
+    generate::{maze, shortest_path, Generated, Generator},
+    grid::Grid,
+};
+use rand::{rngs::StdRng, seq::SliceRandom};
+
+impl Generator for Day18 {
+    const SIZE: usize = DIMENSION;
+
+    /// Falling bytes for the memory space of the actual puzzle, its size is
+    /// fixed so `size` is ignored.
+    fn generate(rng: &mut StdRng, _size: usize) -> Generated {
+        bytes(rng, DIMENSION, FALLEN_BYTES)
+    }
+}
+
+/// The bytes falling into a memory space of `dimension` by `dimension`.
+///
+/// The walls of a perfect maze fall first and the tiles of its only path
+/// from the top left to the bottom right corner last, so the first tile of
+/// the path cuts off the exit. The shortest path is planted for the first
+/// `fallen` bytes.
+pub fn bytes(rng: &mut StdRng, dimension: usize, fallen: usize) -> Generated {
+    // a maze with its outer wall removed, so that the corners are open
+    let maze = maze(rng, dimension + 2, dimension + 2);
+    let dimension = maze.width() - 2;
+    let start = (1, 1);
+    let end = (dimension, dimension);
+
+    let mut walls: Vec<(usize, usize)> = maze
+        .positions()
+        .filter(|&(x, y)| {
+            maze[(x, y)] && (1..=dimension).contains(&x) && (1..=dimension).contains(&y)
+        })
+        .collect();
+    walls.shuffle(rng);
+    let mut path = shortest_path(&maze, start, end).expect("A perfect maze connects all cells");
+    path.retain(|&position| position != start && position != end);
+    path.shuffle(rng);
+
+    let bytes: Vec<(usize, usize)> = walls
+        .into_iter()
+        .chain(path)
+        .map(|(x, y)| (x - 1, y - 1))
+        .collect();
+
+    let mut memory = Grid::filled(dimension, dimension, false);
+    for &byte in bytes.iter().take(fallen) {
+        memory[byte] = true;
+    }
+    let steps = shortest_path(&memory, (0, 0), (dimension - 1, dimension - 1))
+        .map_or(0, |path| path.len() - 1);
+    let blocking = bytes
+        .iter()
+        .skip(fallen)
+        .find(|&&(x, y)| !maze[(x + 1, y + 1)]);
+
+    let lines: Vec<String> = bytes.iter().map(|(x, y)| format!("{x},{y}")).collect();
+    let generated = Generated::new(lines.join("\n")).with_part1(steps);
+    match blocking {
+        Some((x, y)) => generated.with_part2(format!("{x},{y}")),
+        None => generated,
+    }
+}
@@ -0,0 +1,209 @@
+pub mod generate;
+pub mod render;
+
+use anyhow::{bail, ensure, Context, Result};
+use aoc_common::{
+    error::parse_number,
+    grid::Grid,
+    pathfinding::{astar, bfs},
+    ParseError, Point, Solution,
+};
+use std::fmt::Display;
+
+// the size of the memory space and the bytes fallen so far in the actual puzzle
+const DIMENSION: usize = 71;
+const FALLEN_BYTES: usize = 1024;
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = CorruptedMemory;
+
+    fn parse(data: &str) -> Result<Self::Input> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display> {
+        shortest_path(input, FALLEN_BYTES, DIMENSION)
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display> {
+        exit_position(input, DIMENSION)
+    }
+}
+
+pub fn calculate_shortest_path(
+    filename: &str,
+    corrupted_cells: usize,
+    dimension: usize,
+) -> Result<usize> {
+    shortest_path(&Day18::parse_file(filename)?, corrupted_cells, dimension)
+}
+
+pub fn prevent_exit_position(filename: &str, dimension: usize) -> Result<Position> {
+    exit_position(&Day18::parse_file(filename)?, dimension)
+}
+
+fn shortest_path(
+    memory: &CorruptedMemory,
+    corrupted_cells: usize,
+    dimension: usize,
+) -> Result<usize> {
+    let (_, length) = escape_route(memory, corrupted_cells, dimension)?;
+    Ok(length)
+}
+
+// one of the shortest paths from the top left to the exit and its length
+fn escape_route(
+    memory: &CorruptedMemory,
+    corrupted_cells: usize,
+    dimension: usize,
+) -> Result<(Vec<(usize, usize)>, usize)> {
+    let corrupted = fallen_bytes(memory, corrupted_cells, dimension)?;
+    let exit = (dimension - 1, dimension - 1);
+
+    let steps = |&position: &(usize, usize)| {
+        open_neighbors(&corrupted, position).map(|neighbor| (neighbor, 1))
+    };
+    let distance = |&(x, y): &(usize, usize)| exit.0 - x + exit.1 - y;
+
+    astar((0, 0), steps, distance, |&position| position == exit)
+        .context("The exit can't be reached")
+}
+
+// the first byte that blocks the exit, the exit stays blocked by every
+// further byte so the number of bytes can be searched for
+fn exit_position(memory: &CorruptedMemory, dimension: usize) -> Result<Position> {
+    check_bytes(&memory.0, dimension)?;
+    let counts: Vec<usize> = (0..=memory.0.len()).collect();
+    let blocked = counts.partition_point(|&bytes| {
+        let corrupted = corrupted_memory(&memory.0[..bytes], dimension);
+        let paths = bfs([(0, 0)], |&position| open_neighbors(&corrupted, position));
+        paths.distance(&(dimension - 1, dimension - 1)).is_some()
+    });
+
+    match blocked {
+        0 => bail!("The exit is blocked from the start"),
+        bytes if bytes > memory.0.len() => bail!("The bytes never block the exit"),
+        bytes => Ok(memory.0[bytes - 1]),
+    }
+}
+
+fn open_neighbors(
+    corrupted: &Grid<bool>,
+    position: (usize, usize),
+) -> impl Iterator<Item = (usize, usize)> + '_ {
+    corrupted
+        .neighbors4(position)
+        .filter(|&neighbor| !corrupted[neighbor])
+}
+
+// the memory space after the first `count` bytes have fallen
+fn fallen_bytes(memory: &CorruptedMemory, count: usize, dimension: usize) -> Result<Grid<bool>> {
+    let bytes = memory
+        .0
+        .get(..count)
+        .with_context(|| format!("Only {} bytes fall, not {count}", memory.0.len()))?;
+    check_bytes(bytes, dimension)?;
+    Ok(corrupted_memory(bytes, dimension))
+}
+
+// the size of the memory space is only known when solving, not while parsing
+fn check_bytes(bytes: &[Position], dimension: usize) -> Result<()> {
+    ensure!(dimension > 0, "The memory space is empty");
+    for (index, pos) in bytes.iter().enumerate() {
+        ensure!(
+            pos.x < dimension && pos.y < dimension,
+            "Byte {} at {pos} falls outside of the {dimension}x{dimension} memory space",
+            index + 1
+        );
+    }
+    Ok(())
+}
+
+// the bytes have to be inside the memory space, see `check_bytes`
+fn corrupted_memory(bytes: &[Position], dimension: usize) -> Grid<bool> {
+    let mut corrupted = Grid::filled(dimension, dimension, false);
+    for pos in bytes {
+        corrupted[(pos.x, pos.y)] = true;
+    }
+    corrupted
+}
+
+pub fn parse(data: &str) -> Result<CorruptedMemory> {
+    let positions = data
+        .lines()
+        .enumerate()
+        .map(|(index, line)| parse_position(index, line))
+        .collect::<Result<_, _>>()?;
+    Ok(CorruptedMemory(positions))
+}
+
+pub struct CorruptedMemory(Vec<Position>);
+
+pub type Position = Point<usize>;
+
+fn parse_position(index: usize, line: &str) -> Result<Position, ParseError> {
+    let (x, y) = line
+        .split_once(',')
+        .ok_or_else(|| ParseError::in_line(index, line, line.len(), "','"))?;
+    Ok(Position::new(
+        parse_number(index, line, x)?,
+        parse_number(index, line, y)?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::answers::expected;
+
+    #[test]
+    fn test_invalid_position() {
+        let result = parse("5,4\n4,x2\n");
+        let err = result.err().unwrap().downcast::<ParseError>().unwrap();
+        assert_eq!(Some('x'), err.found());
+        assert_eq!((Some(2), Some(3)), (err.line(), err.column()))
+    }
+
+    #[test]
+    fn test_bytes_outside() {
+        let memory = parse("5,4\n4,7\n").unwrap();
+        assert!(shortest_path(&memory, 1, 7).is_ok());
+        assert!(shortest_path(&memory, 2, 8).is_ok());
+
+        let err = shortest_path(&memory, 2, 7).unwrap_err();
+        assert!(err.to_string().contains("outside"));
+        assert!(exit_position(&memory, 7).is_err());
+        assert!(shortest_path(&memory, 3, 8).is_err());
+        assert!(parse("5,4\n-4,2\n").is_err());
+    }
+
+    #[test]
+    fn test_small_a() {
+        let result = calculate_shortest_path("input_small.txt", 12, 7);
+        assert!(result.is_ok());
+        assert_eq!(expected(1, "input_small.txt"), result.unwrap().to_string())
+    }
+
+    #[test]
+    fn test_input_a() {
+        let result = calculate_shortest_path("input.txt", 1024, 71);
+        assert!(result.is_ok());
+        assert_eq!(expected(1, "input.txt"), result.unwrap().to_string())
+    }
+
+    #[test]
+    fn test_small_b() {
+        let result = prevent_exit_position("input_small.txt", 7);
+        assert!(result.is_ok());
+        assert_eq!(expected(2, "input_small.txt"), result.unwrap().to_string())
+    }
+
+    #[test]
+    fn test_input_b() {
+        let result = prevent_exit_position("input.txt", 71);
+        assert!(result.is_ok());
+        assert_eq!(expected(2, "input.txt"), result.unwrap().to_string())
+    }
+}
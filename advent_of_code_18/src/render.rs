@@ -0,0 +1,42 @@
+use crate::{escape_route, fallen_bytes, Day18, DIMENSION, FALLEN_BYTES};
+use anyhow::Result;
+use aoc_common::render::{Canvas, Cell, Render, DARK, GREEN, RED};
+
+impl Render for Day18 {
+    /// The corrupted memory and the shortest way out after the first
+    /// kilobyte has fallen.
+    fn render(input: &Self::Input) -> Result<Canvas> {
+        let (path, _) = escape_route(input, FALLEN_BYTES, DIMENSION)?;
+
+        let corrupted = fallen_bytes(input, FALLEN_BYTES, DIMENSION)?;
+        let mut canvas = corrupted.map(|&corrupted| match corrupted {
+            true => Cell::new('#', RED),
+            false => Cell::new('.', DARK),
+        });
+        for position in path {
+            canvas[position] = Cell::new('O', GREEN);
+        }
+
+        Ok(canvas)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{answers::expected, render::snapshot};
+
+    #[test]
+    fn test_render() {
+        let data = std::fs::read_to_string("input.txt").unwrap();
+        let result = snapshot::<Day18>(&data);
+        assert!(result.is_ok());
+
+        let canvas = result.unwrap();
+        assert_eq!((DIMENSION, DIMENSION), (canvas.width(), canvas.height()));
+        let corrupted = canvas.cells().filter(|cell| cell.symbol == '#').count();
+        let path = canvas.cells().filter(|cell| cell.symbol == 'O').count();
+        assert!(corrupted <= FALLEN_BYTES);
+        assert_eq!(expected(1, "input.txt"), (path - 1).to_string());
+    }
+}
@@ -0,0 +1,45 @@
+use crate::Day19;
+use aoc_common::generate::{Generated, Generator};
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+
+// no towel has a green stripe, so every design with one is impossible
+const STRIPES: [char; 4] = ['w', 'u', 'b', 'r'];
+
+impl Generator for Day19 {
+    const SIZE: usize = 400;
+
+    /// Fifty towels and `size` designs, each made from towels and about a
+    /// third of them spoiled by a green stripe.
+    fn generate(rng: &mut StdRng, size: usize) -> Generated {
+        let mut towels: Vec<String> = (0..50)
+            .map(|_| {
+                let length = rng.gen_range(1..=8);
+                (0..length).map(|_| *STRIPES.choose(rng).unwrap()).collect()
+            })
+            .collect();
+        towels.sort();
+        towels.dedup();
+        towels.shuffle(rng);
+
+        let mut possible = 0;
+        let designs: Vec<String> = (0..size)
+            .map(|_| {
+                let mut design = String::new();
+                let length = rng.gen_range(20..=60);
+                while design.len() < length {
+                    design += towels.choose(rng).unwrap();
+                }
+
+                if rng.gen_bool(0.3) {
+                    design.insert(rng.gen_range(0..=design.len()), 'g');
+                } else {
+                    possible += 1;
+                }
+                design
+            })
+            .collect();
+
+        Generated::new(format!("{}\n\n{}", towels.join(", "), designs.join("\n")))
+            .with_part1(possible)
+    }
+}
@@ -0,0 +1,221 @@
+pub mod generate;
+
+use anyhow::{Context, Result};
+use aoc_common::{error::offset_in, ParseError, Solution};
+use std::{collections::BTreeMap, fmt::Display};
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = (Towels, Designs);
+
+    fn parse(data: &str) -> Result<Self::Input> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display> {
+        designs_possible(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display> {
+        possible_design_count(input)
+    }
+}
+
+pub fn possible_designs(filename: &str) -> Result<usize> {
+    designs_possible(&Day19::parse_file(filename)?)
+}
+
+pub fn count_possible_designs(filename: &str) -> Result<usize> {
+    possible_design_count(&Day19::parse_file(filename)?)
+}
+
+fn designs_possible((towels, designs): &(Towels, Designs)) -> Result<usize> {
+    let mut memo = BTreeMap::new();
+
+    let sum = designs
+        .0
+        .iter()
+        .filter(|design| towels.can_create_design(design, &mut memo))
+        .count();
+
+    Ok(sum)
+}
+
+fn possible_design_count((towels, designs): &(Towels, Designs)) -> Result<usize> {
+    let mut memo = BTreeMap::new();
+
+    let sum = designs
+        .0
+        .iter()
+        .map(|design| towels.count_possible_designs(design, &mut memo))
+        .sum();
+
+    Ok(sum)
+}
+
+pub fn parse(data: &str) -> Result<(Towels, Designs)> {
+    let mut lines = data.lines();
+
+    let line = lines.next().context("No towel patterns")?;
+    let towels = line
+        .split(',')
+        .map(|field| match field.trim() {
+            // an empty pattern would fit every design without ever finishing it
+            "" => Err(ParseError::in_line(
+                0,
+                line,
+                offset_in(line, field) + field.len(),
+                "a towel pattern",
+            )),
+            towel => Ok(towel.to_string()),
+        })
+        .collect::<Result<_, _>>()?;
+
+    // the designs follow after a blank line
+    let designs = lines
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(str::to_string)
+        .collect();
+
+    Ok((Towels(towels), Designs(designs)))
+}
+
+pub struct Towels(Vec<String>);
+
+impl Towels {
+    fn can_create_design(&self, design: &str, memo: &mut BTreeMap<String, bool>) -> bool {
+        if design.is_empty() {
+            return true;
+        }
+        if let Some(result) = memo.get(design) {
+            return *result;
+        }
+
+        for towel in &self.0 {
+            if design.starts_with(towel) && self.can_create_design(&design[towel.len()..], memo) {
+                memo.insert(design.to_string(), true);
+                return true;
+            }
+        }
+
+        memo.insert(design.to_string(), false);
+        false
+    }
+
+    fn count_possible_designs(&self, design: &str, memo: &mut BTreeMap<String, usize>) -> usize {
+        if design.is_empty() {
+            return 1;
+        }
+        if let Some(result) = memo.get(design) {
+            return *result;
+        }
+
+        let sum = self
+            .0
+            .iter()
+            .filter(|&towel| design.starts_with(towel))
+            .map(|towel| self.count_possible_designs(&design[towel.len()..], memo))
+            .sum();
+
+        memo.insert(design.to_string(), sum);
+        sum
+    }
+}
+
+pub struct Designs(Vec<String>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{answers::expected, normalize};
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_empty_pattern() {
+        let result = parse("r,b,\n\nrb\n");
+        let err = result.err().unwrap().downcast::<ParseError>().unwrap();
+        assert_eq!(
+            (Some(1), Some(5), None),
+            (err.line(), err.column(), err.found())
+        );
+
+        let result = parse("r, , b\n\nrb\n");
+        let err = result.err().unwrap().downcast::<ParseError>().unwrap();
+        assert_eq!(
+            (Some(1), Some(4), Some(',')),
+            (err.line(), err.column(), err.found())
+        );
+    }
+
+    /// Counts the arrangements without memoization, only usable for short designs.
+    fn count_naive(towels: &[String], design: &str) -> usize {
+        if design.is_empty() {
+            return 1;
+        }
+
+        towels
+            .iter()
+            .filter(|&towel| design.starts_with(towel))
+            .map(|towel| count_naive(towels, &design[towel.len()..]))
+            .sum()
+    }
+
+    #[test]
+    fn test_small_a() {
+        let result = possible_designs("input_small.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(1, "input_small.txt"), result.unwrap().to_string())
+    }
+
+    #[test]
+    fn test_input_a() {
+        let result = possible_designs("input.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(1, "input.txt"), result.unwrap().to_string())
+    }
+
+    #[test]
+    fn test_small_b() {
+        let result = count_possible_designs("input_small.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(2, "input_small.txt"), result.unwrap().to_string())
+    }
+
+    #[test]
+    fn test_input_b() {
+        let result = count_possible_designs("input.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(2, "input.txt"), result.unwrap().to_string())
+    }
+
+    #[test]
+    fn test_untidy_input() {
+        let untidy = "\u{feff}r, wr, b \r\n\r\n\r\nbrwr\r\nrrbgbr\r\n\r\nwrr  \r\n";
+        let result = parse(&normalize(untidy));
+        assert!(result.is_ok());
+        let (towels, designs) = result.unwrap();
+        assert_eq!(vec!["r", "wr", "b"], towels.0);
+        assert_eq!(vec!["brwr", "rrbgbr", "wrr"], designs.0);
+    }
+
+    proptest! {
+        #[test]
+        fn test_solvers_agree(
+            towels in prop::collection::vec("[wubrg]{1,3}", 1..6),
+            designs in prop::collection::vec("[wubrg]{0,10}", 1..5),
+        ) {
+            // the memos are shared between designs, like in the solutions
+            let towels = Towels(towels);
+            let mut possible = BTreeMap::new();
+            let mut counts = BTreeMap::new();
+
+            for design in &designs {
+                let count = towels.count_possible_designs(design, &mut counts);
+                prop_assert_eq!(count_naive(&towels.0, design), count);
+                prop_assert_eq!(count > 0, towels.can_create_design(design, &mut possible));
+            }
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use crate::{Day20, MIN_SAVING};
+use aoc_common::{
+    generate::{maze, render_maze, shortest_path, Generated, Generator},
+    grid::Grid,
+};
+use rand::rngs::StdRng;
+
+impl Generator for Day20 {
+    const SIZE: usize = 141;
+
+    /// A race track through a map of `size` by `size`, the only path through
+    /// a perfect maze with all other cells turned into walls.
+    fn generate(rng: &mut StdRng, size: usize) -> Generated {
+        let maze = maze(rng, size, size);
+        let start = (1, maze.height() - 2);
+        let end = (maze.width() - 2, 1);
+        let track = shortest_path(&maze, start, end).expect("A perfect maze connects all cells");
+
+        let mut map = Grid::filled(maze.width(), maze.height(), true);
+        for &position in &track {
+            map[position] = false;
+        }
+
+        Generated::new(render_maze(&map, start, end))
+            .with_part1(cheats(&track, 2, MIN_SAVING))
+            .with_part2(cheats(&track, 20, MIN_SAVING))
+    }
+}
+
+/// Counts the cheats of up to `duration` picoseconds that save at least
+/// `saving` picoseconds, the track is in the order of the race.
+fn cheats(track: &[(usize, usize)], duration: usize, saving: usize) -> usize {
+    let mut count = 0;
+    for (i, from) in track.iter().enumerate() {
+        for (j, to) in track.iter().enumerate().skip(i + saving) {
+            let distance = from.0.abs_diff(to.0) + from.1.abs_diff(to.1);
+            if distance <= duration && j - i >= saving + distance {
+                count += 1;
+            }
+        }
+    }
+    count
+}
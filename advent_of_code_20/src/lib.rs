@@ -0,0 +1,184 @@
+pub mod generate;
+pub mod render;
+
+use anyhow::{Context, Result};
+use aoc_common::{grid::Grid, pathfinding::bfs, ParseError, Point, Solution};
+use std::fmt::Display;
+
+// the actual puzzle only counts cheats that save at least 100 picoseconds
+const MIN_SAVING: usize = 100;
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = RaceTrack;
+
+    fn parse(data: &str) -> Result<Self::Input> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display> {
+        Ok(input.count_cheats(2, MIN_SAVING))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display> {
+        Ok(input.count_cheats(20, MIN_SAVING))
+    }
+}
+
+pub fn calculate_racetrack_options(
+    filename: &str,
+    cheat_duration: usize,
+    save_time: usize,
+) -> Result<usize> {
+    let race_track = Day20::parse_file(filename)?;
+    Ok(race_track.count_cheats(cheat_duration, save_time))
+}
+
+pub fn parse(data: &str) -> Result<RaceTrack> {
+    RaceTrack::new(&Map::parse(data)?)
+}
+
+type Position = Point<usize>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Field {
+    Track,
+    Wall,
+    Start,
+    End,
+}
+
+impl TryFrom<char> for Field {
+    type Error = ParseError;
+
+    fn try_from(c: char) -> Result<Self, ParseError> {
+        match c {
+            '#' => Ok(Field::Wall),
+            '.' => Ok(Field::Track),
+            'S' => Ok(Field::Start),
+            'E' => Ok(Field::End),
+            _ => Err(ParseError::new(Some(c), "'#', '.', 'S' or 'E'")),
+        }
+    }
+}
+
+struct Map {
+    data: Grid<Field>,
+}
+
+impl Map {
+    fn parse(file: &str) -> Result<Map> {
+        let data = file.parse()?;
+
+        Ok(Map { data })
+    }
+
+    fn get_neighbors(&self, pos: Position) -> impl Iterator<Item = Position> + '_ {
+        self.data
+            .neighbors4(pos)
+            .filter(|neighbor| self.data[*neighbor] != Field::Wall)
+            .map(Position::from)
+    }
+}
+
+pub struct RaceTrack {
+    track: Vec<Position>,
+    width: usize,
+    height: usize,
+}
+
+impl RaceTrack {
+    // the race track is the only path from the start to the end
+    fn new(map: &Map) -> Result<RaceTrack> {
+        let start = map
+            .data
+            .find(&Field::Start)
+            .context("No start found on race track")?;
+        let end = map
+            .data
+            .find(&Field::End)
+            .context("No end found on race track")?;
+
+        let paths = bfs([start.into()], |&position| map.get_neighbors(position));
+        let track = paths
+            .path_to(&end.into())
+            .context("The race track doesn't reach the end")?;
+
+        Ok(RaceTrack {
+            track,
+            width: map.data.width(),
+            height: map.data.height(),
+        })
+    }
+
+    // a cheat can connect any two positions on the track that are at most
+    // `max_duration` apart, it saves the track distance minus the cheat distance
+    fn count_cheats(&self, max_duration: usize, min_saving: usize) -> usize {
+        self.track
+            .iter()
+            .enumerate()
+            .map(|(start, start_pos)| {
+                self.track
+                    .iter()
+                    .enumerate()
+                    .skip(start + min_saving)
+                    .filter(|(end, end_pos)| {
+                        let distance = start_pos.manhattan(**end_pos);
+                        distance <= max_duration && end - start >= min_saving + distance
+                    })
+                    .count()
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::answers::expected;
+
+    #[test]
+    fn test_count_short_cheats() {
+        let race_track = Day20::parse_file("input_small.txt").unwrap();
+        assert_eq!(1, race_track.count_cheats(2, 64));
+        assert_eq!(2, race_track.count_cheats(2, 40));
+        assert_eq!(16, race_track.count_cheats(2, 6));
+    }
+
+    #[test]
+    fn test_count_long_cheats() {
+        let race_track = Day20::parse_file("input_small.txt").unwrap();
+        assert_eq!(3, race_track.count_cheats(20, 76));
+        assert_eq!(7, race_track.count_cheats(20, 74));
+        assert_eq!(29, race_track.count_cheats(20, 72));
+    }
+
+    #[test]
+    fn test_small_a() {
+        let result = calculate_racetrack_options("input_small.txt", 2, 20);
+        assert!(result.is_ok());
+        assert_eq!(5, result.unwrap())
+    }
+
+    #[test]
+    fn test_input_a() {
+        let result = calculate_racetrack_options("input.txt", 2, 100);
+        assert!(result.is_ok());
+        assert_eq!(expected(1, "input.txt"), result.unwrap().to_string())
+    }
+
+    #[test]
+    fn test_small_b() {
+        let result = calculate_racetrack_options("input_small.txt", 20, 50);
+        assert!(result.is_ok());
+        assert_eq!(285, result.unwrap())
+    }
+
+    #[test]
+    fn test_input_b() {
+        let result = calculate_racetrack_options("input.txt", 20, 100);
+        assert!(result.is_ok());
+        assert_eq!(expected(2, "input.txt"), result.unwrap().to_string())
+    }
+}
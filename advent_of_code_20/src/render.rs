@@ -0,0 +1,60 @@
+use crate::{Day20, RaceTrack};
+use anyhow::Result;
+use aoc_common::{
+    grid::Grid,
+    render::{Canvas, Cell, Color, Render, BLUE, DARK, RED},
+};
+
+impl Render for Day20 {
+    /// The race track fading from blue at the start to red at the end.
+    fn render(input: &Self::Input) -> Result<Canvas> {
+        Ok(input.canvas())
+    }
+}
+
+impl RaceTrack {
+    fn canvas(&self) -> Canvas {
+        let mut canvas = Grid::filled(self.width, self.height, Cell::new('#', DARK));
+
+        let last = self.track.len().saturating_sub(1).max(1);
+        for (index, &position) in self.track.iter().enumerate() {
+            let symbol = match index {
+                0 => 'S',
+                _ if index == self.track.len() - 1 => 'E',
+                _ => '.',
+            };
+            canvas[position] = Cell::new(symbol, blend(BLUE, RED, index, last));
+        }
+
+        canvas
+    }
+}
+
+// the color `step` of `steps` on the way from `from` to `to`
+fn blend(from: Color, to: Color, step: usize, steps: usize) -> Color {
+    let mut color = from;
+    for (channel, (from, to)) in color.iter_mut().zip(from.into_iter().zip(to)) {
+        let value = from as usize * (steps - step) + to as usize * step;
+        *channel = (value / steps) as u8;
+    }
+    color
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    #[test]
+    fn test_render() {
+        let track = parse("#####\n#S#E#\n#...#\n#####\n").unwrap();
+        let result = Day20::render(&track);
+        assert!(result.is_ok());
+
+        let canvas = result.unwrap();
+        assert_eq!("#####\n#S#E#\n#...#\n#####\n", canvas.to_string());
+        assert_eq!(BLUE, canvas[(1, 1)].color);
+        assert_eq!(RED, canvas[(3, 1)].color);
+        assert_eq!(blend(BLUE, RED, 2, 4), canvas[(2, 2)].color);
+    }
+}
@@ -0,0 +1,16 @@
+use crate::Day21;
+use aoc_common::generate::{Generated, Generator};
+use rand::{rngs::StdRng, Rng};
+
+impl Generator for Day21 {
+    const SIZE: usize = 5;
+
+    /// `size` door codes of three digits followed by an `A`.
+    fn generate(rng: &mut StdRng, size: usize) -> Generated {
+        let codes: Vec<String> = (0..size)
+            .map(|_| format!("{:03}A", rng.gen_range(1..1000)))
+            .collect();
+
+        Generated::new(codes.join("\n"))
+    }
+}
@@ -0,0 +1,286 @@
+pub mod generate;
+
+use anyhow::{Context, Result};
+use aoc_common::{ParseError, Solution};
+use std::{collections::HashMap, fmt::Display};
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Vec<String>;
+
+    fn parse(data: &str) -> Result<Self::Input> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display> {
+        complexity(input, 2)
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display> {
+        complexity(input, 25)
+    }
+}
+
+pub fn calculate_complexity(filename: &str, robots: usize) -> Result<u64> {
+    complexity(&Day21::parse_file(filename)?, robots)
+}
+
+fn complexity(codes: &[String], robots: usize) -> Result<u64> {
+    let mut costs = KeypadCosts::new();
+
+    let mut sum = 0;
+    for code in codes {
+        let length = costs.code_cost(code, robots);
+        let value: u64 = code
+            .trim_end_matches('A')
+            .parse()
+            .context("Code without numeric part")?;
+        sum += length * value;
+    }
+
+    Ok(sum)
+}
+
+pub fn parse(data: &str) -> Result<Vec<String>> {
+    let codes = data
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(index, line)| parse_code(index, line))
+        .collect::<Result<_, _>>()?;
+
+    Ok(codes)
+}
+
+// a code like `029A`, its number has to be positive or the complexity is zero
+fn parse_code(index: usize, line: &str) -> Result<String, ParseError> {
+    let end = line
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(line.len());
+    match &line[end..] {
+        "A" => {}
+        rest if rest.starts_with('A') => {
+            return Err(ParseError::in_line(
+                index,
+                line,
+                end + 1,
+                "the end of the code",
+            ));
+        }
+        _ => return Err(ParseError::in_line(index, line, end, "a digit or 'A'")),
+    }
+    if line[..end].trim_start_matches('0').is_empty() {
+        return Err(ParseError::in_line(
+            index,
+            line,
+            0,
+            "a code with a positive number",
+        ));
+    }
+
+    Ok(line.to_string())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Position(i32, i32);
+
+struct Keypad {
+    keys: HashMap<char, Position>,
+    gap: Position,
+}
+
+impl Keypad {
+    fn new(layout: &[&str]) -> Keypad {
+        let mut keys = HashMap::new();
+        let mut gap = Position(0, 0);
+
+        for (y, row) in layout.iter().enumerate() {
+            for (x, key) in row.chars().enumerate() {
+                let position = Position(x as i32, y as i32);
+                if key == ' ' {
+                    gap = position;
+                } else {
+                    keys.insert(key, position);
+                }
+            }
+        }
+
+        Keypad { keys, gap }
+    }
+
+    fn numeric() -> Keypad {
+        Keypad::new(&["789", "456", "123", " 0A"])
+    }
+
+    fn directional() -> Keypad {
+        Keypad::new(&[" ^A", "<v>"])
+    }
+
+    // the shortest sequences never zig-zag, so we only have to check moving
+    // horizontally or vertically first, as long as we don't cross the gap
+    fn get_paths(&self, from: char, to: char) -> Vec<String> {
+        let start = self.keys[&from];
+        let end = self.keys[&to];
+
+        let dx = end.0 - start.0;
+        let dy = end.1 - start.1;
+        let horizontal = if dx < 0 { "<" } else { ">" }.repeat(dx.unsigned_abs() as usize);
+        let vertical = if dy < 0 { "^" } else { "v" }.repeat(dy.unsigned_abs() as usize);
+
+        let mut paths = Vec::new();
+        if Position(end.0, start.1) != self.gap {
+            paths.push(format!("{horizontal}{vertical}A"));
+        }
+        if Position(start.0, end.1) != self.gap {
+            paths.push(format!("{vertical}{horizontal}A"));
+        }
+        paths.dedup();
+
+        paths
+    }
+}
+
+struct KeypadCosts {
+    numeric: Keypad,
+    directional: Keypad,
+    cache: HashMap<(char, char, usize), u64>,
+}
+
+impl KeypadCosts {
+    fn new() -> KeypadCosts {
+        KeypadCosts {
+            numeric: Keypad::numeric(),
+            directional: Keypad::directional(),
+            cache: HashMap::new(),
+        }
+    }
+
+    // number of button presses on our own keypad to type the code on the
+    // numeric keypad, with `robots` directional keypads in between
+    fn code_cost(&mut self, code: &str, robots: usize) -> u64 {
+        let mut cost = 0;
+        let mut current = 'A';
+
+        for key in code.chars() {
+            let paths = self.numeric.get_paths(current, key);
+            cost += paths
+                .iter()
+                .map(|path| self.sequence_cost(path, robots))
+                .min()
+                .unwrap();
+            current = key;
+        }
+
+        cost
+    }
+
+    // every sequence on a directional keypad starts and ends on `A`
+    fn sequence_cost(&mut self, sequence: &str, robots: usize) -> u64 {
+        if robots == 0 {
+            return sequence.len() as u64;
+        }
+
+        let mut cost = 0;
+        let mut current = 'A';
+        for key in sequence.chars() {
+            cost += self.move_cost(current, key, robots);
+            current = key;
+        }
+
+        cost
+    }
+
+    fn move_cost(&mut self, from: char, to: char, robots: usize) -> u64 {
+        if let Some(cost) = self.cache.get(&(from, to, robots)) {
+            return *cost;
+        }
+
+        let paths = self.directional.get_paths(from, to);
+        let cost = paths
+            .iter()
+            .map(|path| self.sequence_cost(path, robots - 1))
+            .min()
+            .unwrap();
+
+        self.cache.insert((from, to, robots), cost);
+        cost
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::answers::expected;
+
+    #[test]
+    fn test_code_cost() {
+        let mut costs = KeypadCosts::new();
+        assert_eq!(12, costs.code_cost("029A", 0));
+        assert_eq!(28, costs.code_cost("029A", 1));
+        assert_eq!(68, costs.code_cost("029A", 2));
+        assert_eq!(64, costs.code_cost("379A", 2));
+    }
+
+    #[test]
+    fn test_small_a() {
+        let result = calculate_complexity("input_small.txt", 2);
+        assert!(result.is_ok());
+        assert_eq!(expected(1, "input_small.txt"), result.unwrap().to_string())
+    }
+
+    #[test]
+    #[ignore = "input.txt is not checked in yet"]
+    fn test_input_a() {
+        let result = calculate_complexity("input.txt", 2);
+        assert!(result.is_ok());
+        assert_eq!(expected(1, "input.txt"), result.unwrap().to_string())
+    }
+
+    #[test]
+    fn test_invalid_code() {
+        let result = parse("029A\n0A\n");
+        let err = result.err().unwrap().downcast::<ParseError>().unwrap();
+        assert_eq!((Some(2), Some(1)), (err.line(), err.column()));
+
+        let result = parse("A\n");
+        let err = result.err().unwrap().downcast::<ParseError>().unwrap();
+        assert_eq!(
+            (Some(1), Some(1), Some('A')),
+            (err.line(), err.column(), err.found())
+        );
+
+        let result = parse("02B9A\n");
+        let err = result.err().unwrap().downcast::<ParseError>().unwrap();
+        assert_eq!(
+            (Some(1), Some(3), Some('B')),
+            (err.line(), err.column(), err.found())
+        );
+
+        let result = parse("02A9\n");
+        let err = result.err().unwrap().downcast::<ParseError>().unwrap();
+        assert_eq!(
+            (Some(1), Some(4), Some('9')),
+            (err.line(), err.column(), err.found())
+        );
+
+        let result = parse("029\n");
+        let err = result.err().unwrap().downcast::<ParseError>().unwrap();
+        assert_eq!((Some(1), None), (err.line(), err.found()));
+    }
+
+    #[test]
+    fn test_small_b() {
+        let result = calculate_complexity("input_small.txt", 25);
+        assert!(result.is_ok());
+        assert_eq!(expected(2, "input_small.txt"), result.unwrap().to_string())
+    }
+
+    #[test]
+    #[ignore = "input.txt is not checked in yet"]
+    fn test_input_b() {
+        let result = calculate_complexity("input.txt", 25);
+        assert!(result.is_ok());
+        assert_eq!(expected(2, "input.txt"), result.unwrap().to_string())
+    }
+}
@@ -0,0 +1,7 @@
+use advent_of_code_21::Day21;
+use anyhow::Result;
+use aoc_common::answer;
+
+fn main() -> Result<()> {
+    answer::main::<Day21>(21, &[1, 2])
+}
@@ -0,0 +1,16 @@
+use crate::Day22;
+use aoc_common::generate::{Generated, Generator};
+use rand::{rngs::StdRng, Rng};
+
+impl Generator for Day22 {
+    const SIZE: usize = 2000;
+
+    /// The initial secret numbers of `size` buyers.
+    fn generate(rng: &mut StdRng, size: usize) -> Generated {
+        let secrets: Vec<String> = (0..size)
+            .map(|_| rng.gen_range(1..1 << 24).to_string())
+            .collect();
+
+        Generated::new(secrets.join("\n"))
+    }
+}
@@ -0,0 +1,251 @@
+pub mod generate;
+
+use anyhow::{Context, Result};
+use aoc_common::{error::parse_number, Solution};
+use itertools::Itertools;
+use std::{collections::HashSet, fmt::Display};
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Vec<Secret>;
+
+    fn parse(data: &str) -> Result<Self::Input> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display> {
+        secret_number_sum(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display> {
+        max_price(input)
+    }
+}
+
+pub fn sum_secret_numbers(filename: &str) -> Result<u64> {
+    secret_number_sum(&Day22::parse_file(filename)?)
+}
+
+pub fn get_max_price(filename: &str) -> Result<u64> {
+    max_price(&Day22::parse_file(filename)?)
+}
+
+fn secret_number_sum(seeds: &[Secret]) -> Result<u64> {
+    let sum = seeds
+        .iter()
+        .map(|seed| get_secret_number(*seed, 2000).0)
+        .sum();
+    Ok(sum)
+}
+
+fn max_price(seeds: &[Secret]) -> Result<u64> {
+    let secrets: Vec<Vec<Secret>> = seeds
+        .iter()
+        .map(|seed| get_secret_numbers(*seed, 2000))
+        .collect();
+
+    let prices: Vec<Vec<Price>> = secrets.iter().map(|secrets| get_prices(secrets)).collect();
+    let diffs: HashSet<[i64; 4]> = prices
+        .iter()
+        .flat_map(|prices| get_diff_prices(prices, 10))
+        .collect();
+
+    let max = diffs
+        .iter()
+        .map(|diff| {
+            prices
+                .iter()
+                .filter_map(|prices| get_price_for_diff(prices, diff))
+                .sum::<u64>()
+        })
+        .max()
+        .context("No buyers in the input")?;
+
+    Ok(max)
+}
+
+pub fn parse(data: &str) -> Result<Vec<Secret>> {
+    let secrets = data
+        .lines()
+        .enumerate()
+        .map(|(index, line)| parse_number(index, line, line).map(Secret))
+        .collect::<Result<_, _>>()?;
+    Ok(secrets)
+}
+
+fn get_secret_number(start: Secret, iterations: usize) -> Secret {
+    let mut current = start;
+    for _ in 0..iterations {
+        current = current.next();
+    }
+    current
+}
+
+fn get_secret_numbers(start: Secret, iterations: usize) -> Vec<Secret> {
+    let mut current = start;
+    let mut secrets = vec![current];
+    for _ in 0..iterations {
+        current = current.next();
+        secrets.push(current);
+    }
+    secrets
+}
+
+fn get_prices(secrets: &[Secret]) -> Vec<Price> {
+    secrets
+        .iter()
+        .map(|secret| secret.price())
+        .tuple_windows()
+        .map(|(prev, curr)| Price::new(prev, curr))
+        .collect()
+}
+
+fn get_diff_prices(prices: &[Price], count: usize) -> Vec<[i64; 4]> {
+    prices
+        .iter()
+        .tuple_windows()
+        .map(|(a, b, c, d)| ([a.diff, b.diff, c.diff, d.diff], d.total))
+        .sorted_by(|a, b| Ord::cmp(&b.1, &a.1))
+        .take(count)
+        .map(|(diff, _)| diff)
+        .collect()
+}
+
+fn get_price_for_diff(prices: &[Price], diff: &[i64; 4]) -> Option<u64> {
+    prices
+        .iter()
+        .tuple_windows()
+        .find(|(a, b, c, d)| [a.diff, b.diff, c.diff, d.diff] == *diff)
+        .map(|(_, _, _, d)| d.total)
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Secret(u64);
+
+impl Secret {
+    fn next(&self) -> Self {
+        self.step(|value| value * 64)
+            .step(|value| value / 32)
+            .step(|value| value * 2048)
+    }
+
+    fn price(&self) -> u64 {
+        self.0 % 10
+    }
+
+    fn step(&self, op: impl Fn(u64) -> u64) -> Self {
+        let mix = op(self.0);
+        self.mix(mix).prune()
+    }
+
+    fn mix(&self, value: u64) -> Self {
+        let next = self.0 ^ value;
+        Secret(next)
+    }
+
+    fn prune(&self) -> Self {
+        let next = self.0 % 16777216;
+        Secret(next)
+    }
+}
+
+struct Price {
+    total: u64,
+    diff: i64,
+}
+
+impl Price {
+    fn new(prev: u64, current: u64) -> Self {
+        Price {
+            total: current,
+            diff: current as i64 - prev as i64,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::answers::expected;
+
+    #[test]
+    fn test_next_secrets() {
+        let start = Secret(123);
+
+        let next = start.next();
+        assert_eq!(15887950, next.0);
+
+        let next = next.next();
+        assert_eq!(16495136, next.0);
+
+        let next = next.next();
+        assert_eq!(527345, next.0);
+
+        let next = next.next();
+        assert_eq!(704524, next.0);
+
+        let next = next.next();
+        assert_eq!(1553684, next.0);
+
+        let next = next.next();
+        assert_eq!(12683156, next.0);
+
+        let next = next.next();
+        assert_eq!(11100544, next.0);
+
+        let next = next.next();
+        assert_eq!(12249484, next.0);
+
+        let next = next.next();
+        assert_eq!(7753432, next.0);
+
+        let next = next.next();
+        assert_eq!(5908254, next.0);
+    }
+
+    #[test]
+    fn test_small_a() {
+        let result = sum_secret_numbers("input_small.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(1, "input_small.txt"), result.unwrap().to_string())
+    }
+
+    #[test]
+    fn test_input_a() {
+        let result = sum_secret_numbers("input.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(1, "input.txt"), result.unwrap().to_string())
+    }
+
+    #[test]
+    fn test_small_b() {
+        let seeds = [Secret(1), Secret(2), Secret(3), Secret(2024)];
+        let secrets: Vec<Vec<Secret>> = seeds
+            .iter()
+            .map(|seed| get_secret_numbers(*seed, 2000))
+            .collect();
+
+        let prices: Vec<Vec<Price>> = secrets.iter().map(|secrets| get_prices(secrets)).collect();
+
+        let diff: [i64; 4] = [-2, 1, -1, 3];
+        let max = prices
+            .iter()
+            .filter_map(|prices| get_price_for_diff(prices, &diff))
+            .sum::<u64>();
+
+        assert_eq!(23, max)
+    }
+
+    #[test]
+    fn test_no_buyers() {
+        assert!(max_price(&[]).is_err());
+    }
+
+    #[test]
+    fn test_input_b() {
+        let result = get_max_price("input.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(2, "input.txt"), result.unwrap().to_string())
+    }
+}
@@ -0,0 +1,53 @@
+use crate::Day23;
+use aoc_common::generate::{Generated, Generator};
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+use std::collections::BTreeSet;
+
+// the size of the LAN party, random connections hardly form cliques of four
+const PARTY: usize = 13;
+
+impl Generator for Day23 {
+    const SIZE: usize = 520;
+
+    /// A network of `size` computers, at most 676, with four random
+    /// connections each and a planted LAN party of thirteen computers.
+    fn generate(rng: &mut StdRng, size: usize) -> Generated {
+        let mut computers: Vec<String> = (b'a'..=b'z')
+            .flat_map(|a| (b'a'..=b'z').map(move |b| format!("{}{}", a as char, b as char)))
+            .collect();
+        computers.shuffle(rng);
+        computers.truncate(size.max(PARTY));
+
+        let mut connections = BTreeSet::new();
+        let mut connect = |a: &String, b: &String| {
+            if a != b {
+                connections.insert((a.min(b).clone(), a.max(b).clone()));
+            }
+        };
+
+        let party = &computers[..PARTY];
+        for (i, a) in party.iter().enumerate() {
+            for b in &party[i + 1..] {
+                connect(a, b);
+            }
+        }
+        for a in &computers {
+            for _ in 0..4 {
+                connect(a, &computers[rng.gen_range(0..computers.len())]);
+            }
+        }
+
+        let mut lines: Vec<String> = connections
+            .into_iter()
+            .map(|(a, b)| match rng.gen_bool(0.5) {
+                true => format!("{a}-{b}"),
+                false => format!("{b}-{a}"),
+            })
+            .collect();
+        lines.shuffle(rng);
+
+        let mut password = party.to_vec();
+        password.sort();
+        Generated::new(lines.join("\n")).with_part2(password.join(","))
+    }
+}
@@ -0,0 +1,213 @@
+pub mod generate;
+
+use anyhow::Result;
+use aoc_common::{ParseError, Solution};
+use itertools::Itertools;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Display,
+};
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Vec<(String, String)>;
+
+    fn parse(data: &str) -> Result<Self::Input> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display> {
+        connected_sets(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display> {
+        lan_party_password(input)
+    }
+}
+
+pub fn find_connected_sets(filename: &str) -> Result<usize> {
+    connected_sets(&Day23::parse_file(filename)?)
+}
+
+pub fn find_lan_party_password(filename: &str) -> Result<String> {
+    lan_party_password(&Day23::parse_file(filename)?)
+}
+
+fn connected_sets(connections: &[(String, String)]) -> Result<usize> {
+    let t_connections = get_connections_with_t(connections);
+    let triplets = get_connections_with_two_sinks(connections, &t_connections);
+
+    Ok(triplets.len())
+}
+
+fn lan_party_password(connections: &[(String, String)]) -> Result<String> {
+    let graph = get_graph(connections);
+    let party = find_maximum_clique(&graph);
+
+    Ok(party.iter().join(","))
+}
+
+pub fn parse(data: &str) -> Result<Vec<(String, String)>> {
+    let lines = data
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            line.split_once('-')
+                .map(|(a, b)| (a.to_string(), b.to_string()))
+                .ok_or_else(|| ParseError::in_line(index, line, line.len(), "'-'"))
+        })
+        .collect::<Result<_, _>>()?;
+    Ok(lines)
+}
+
+fn get_graph(connections: &[(String, String)]) -> BTreeMap<&str, BTreeSet<&str>> {
+    let mut graph: BTreeMap<&str, BTreeSet<&str>> = BTreeMap::new();
+    for (client_a, client_b) in connections {
+        graph.entry(client_a).or_default().insert(client_b);
+        graph.entry(client_b).or_default().insert(client_a);
+    }
+    graph
+}
+
+fn find_maximum_clique<'a>(graph: &BTreeMap<&'a str, BTreeSet<&'a str>>) -> BTreeSet<&'a str> {
+    let mut maximum = BTreeSet::new();
+    let candidates = graph.keys().copied().collect();
+    bron_kerbosch(
+        graph,
+        BTreeSet::new(),
+        candidates,
+        BTreeSet::new(),
+        &mut maximum,
+    );
+    maximum
+}
+
+// Bron-Kerbosch with pivoting, only the largest clique found is kept
+fn bron_kerbosch<'a>(
+    graph: &BTreeMap<&'a str, BTreeSet<&'a str>>,
+    clique: BTreeSet<&'a str>,
+    mut candidates: BTreeSet<&'a str>,
+    mut excluded: BTreeSet<&'a str>,
+    maximum: &mut BTreeSet<&'a str>,
+) {
+    if candidates.is_empty() {
+        if excluded.is_empty() && clique.len() > maximum.len() {
+            *maximum = clique;
+        }
+        return;
+    }
+    if clique.len() + candidates.len() <= maximum.len() {
+        return;
+    }
+
+    let pivot = candidates
+        .union(&excluded)
+        .max_by_key(|node| graph[*node].len())
+        .copied()
+        .unwrap();
+
+    let nodes: Vec<&str> = candidates.difference(&graph[pivot]).copied().collect();
+    for node in nodes {
+        let neighbors = &graph[node];
+
+        let mut next_clique = clique.clone();
+        next_clique.insert(node);
+        let next_candidates = candidates.intersection(neighbors).copied().collect();
+        let next_excluded = excluded.intersection(neighbors).copied().collect();
+        bron_kerbosch(graph, next_clique, next_candidates, next_excluded, maximum);
+
+        candidates.remove(node);
+        excluded.insert(node);
+    }
+}
+
+fn get_connections_with_t(connections: &[(String, String)]) -> BTreeMap<String, Vec<String>> {
+    let mut map = BTreeMap::new();
+    for (client_a, client_b) in connections {
+        add_connection_with_t(client_a, client_b, &mut map);
+        add_connection_with_t(client_b, client_a, &mut map);
+    }
+    map
+}
+
+fn add_connection_with_t(source: &str, sink: &str, map: &mut BTreeMap<String, Vec<String>>) {
+    if source.starts_with('t') {
+        if let Some(sinks) = map.get_mut(source) {
+            sinks.push(sink.to_string());
+        } else {
+            let sinks = vec![sink.to_string()];
+            map.insert(source.to_string(), sinks);
+        }
+    }
+}
+
+fn get_connections_with_two_sinks(
+    c: &[(String, String)],
+    t: &BTreeMap<String, Vec<String>>,
+) -> BTreeSet<[String; 3]> {
+    let mut sets = BTreeSet::new();
+
+    for (key, values) in t {
+        if values.len() > 1 {
+            for sinks in values.iter().combinations(2) {
+                if contains_connection_between(c, sinks[0], sinks[1]) {
+                    let mut set = [key.clone(), sinks[0].clone(), sinks[1].clone()];
+                    set.sort_unstable();
+                    sets.insert(set);
+                }
+            }
+        }
+    }
+
+    sets
+}
+
+fn contains_connection_between(c: &[(String, String)], source: &str, sink: &str) -> bool {
+    c.iter()
+        .any(|(a, b)| (a == source && b == sink) || (a == sink && b == source))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::answers::expected;
+
+    #[test]
+    fn test_invalid_connection() {
+        let result = parse("kh-tc\nqpcb\n");
+        let err = result.err().unwrap().downcast::<ParseError>().unwrap();
+        assert_eq!(
+            (Some(2), Some(5), None),
+            (err.line(), err.column(), err.found())
+        );
+    }
+
+    #[test]
+    fn test_small_a() {
+        let result = find_connected_sets("input_small.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(1, "input_small.txt"), result.unwrap().to_string())
+    }
+
+    #[test]
+    fn test_input_a() {
+        let result = find_connected_sets("input.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(1, "input.txt"), result.unwrap().to_string())
+    }
+
+    #[test]
+    fn test_small_b() {
+        let result = find_lan_party_password("input_small.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(2, "input_small.txt"), result.unwrap().to_string())
+    }
+
+    #[test]
+    fn test_input_b() {
+        let result = find_lan_party_password("input.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(2, "input.txt"), result.unwrap().to_string())
+    }
+}
@@ -0,0 +1,143 @@
+use crate::Day24;
+use aoc_common::generate::{Generated, Generator};
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+use std::collections::BTreeSet;
+
+impl Generator for Day24 {
+    const SIZE: usize = 45;
+
+    /// A ripple-carry adder for numbers of `size` bits with four pairs of
+    /// swapped outputs, like the actual puzzle.
+    fn generate(rng: &mut StdRng, size: usize) -> Generated {
+        adder(rng, size, 4)
+    }
+}
+
+/// The wires of the full adder of a bit, every output is a separate wire.
+struct Bit {
+    sum: String,
+    input_and: String,
+    carry_and: String,
+    carry: String,
+}
+
+/// A ripple-carry adder for numbers of `bits` bits with `swaps` pairs of
+/// swapped gate outputs, up to one pair in each full adder.
+///
+/// Only swaps the solutions can detect are used: the sum with one of the
+/// other outputs of its full adder, or the outputs of the two input gates.
+/// The swapped wires are planted, and the sum if nothing is swapped.
+pub fn adder(rng: &mut StdRng, bits: usize, swaps: usize) -> Generated {
+    let bits = bits.clamp(2, 63);
+    let swaps = swaps.min(bits - 2);
+
+    // random names that can't be mistaken for the x, y and z wires
+    let mut names = BTreeSet::new();
+    let mut wire = |rng: &mut StdRng| loop {
+        let name: String = (0..3).map(|_| rng.gen_range(b'a'..b'x') as char).collect();
+        if names.insert(name.clone()) {
+            return name;
+        }
+    };
+
+    let x = rng.gen_range(0..1_u64 << bits);
+    let y = rng.gen_range(0..1_u64 << bits);
+    let mut gates: Vec<(String, &str, String, String)> = Vec::new();
+    let mut full_adders = Vec::new();
+    let mut carry = wire(rng);
+    gates.push(("x00".into(), "XOR", "y00".into(), "z00".into()));
+    gates.push(("x00".into(), "AND", "y00".into(), carry.clone()));
+
+    for i in 1..bits {
+        let (xi, yi) = (format!("x{i:02}"), format!("y{i:02}"));
+        let next_carry = match i == bits - 1 {
+            true => format!("z{bits:02}"),
+            false => wire(rng),
+        };
+        let bit = Bit {
+            sum: wire(rng),
+            input_and: wire(rng),
+            carry_and: wire(rng),
+            carry: next_carry,
+        };
+
+        gates.push((xi.clone(), "XOR", yi.clone(), bit.sum.clone()));
+        gates.push((xi, "AND", yi, bit.input_and.clone()));
+        gates.push((bit.sum.clone(), "XOR", carry.clone(), format!("z{i:02}")));
+        gates.push((bit.sum.clone(), "AND", carry, bit.carry_and.clone()));
+        gates.push((
+            bit.input_and.clone(),
+            "OR",
+            bit.carry_and.clone(),
+            bit.carry.clone(),
+        ));
+
+        carry = bit.carry.clone();
+        full_adders.push((i, bit));
+    }
+
+    // the last full adder produces the final carry, leave it alone
+    full_adders.pop();
+    full_adders.shuffle(rng);
+    let mut swapped = Vec::new();
+    for (i, bit) in full_adders.into_iter().take(swaps) {
+        let z = format!("z{i:02}");
+        let (a, b) = match rng.gen_range(0..4) {
+            0 => (z, bit.input_and),
+            1 => (z, bit.carry_and),
+            2 => (z, bit.carry),
+            _ => (bit.sum, bit.input_and),
+        };
+
+        for gate in &mut gates {
+            if gate.3 == a {
+                gate.3 = b.clone();
+            } else if gate.3 == b {
+                gate.3 = a.clone();
+            }
+        }
+        swapped.extend([a, b]);
+    }
+    swapped.sort();
+
+    let mut lines = Vec::new();
+    for (prefix, value) in [('x', x), ('y', y)] {
+        for i in 0..bits {
+            lines.push(format!("{prefix}{i:02}: {}", (value >> i) & 1));
+        }
+    }
+    lines.push(String::new());
+
+    gates.shuffle(rng);
+    for (a, op, b, output) in gates {
+        let (a, b) = if rng.gen_bool(0.5) { (a, b) } else { (b, a) };
+        lines.push(format!("{a} {op} {b} -> {output}"));
+    }
+
+    let generated = Generated::new(lines.join("\n"));
+    match swapped.is_empty() {
+        true => generated.with_part1(x + y),
+        false => generated.with_part2(swapped.join(",")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{output, parse, swapped_wires};
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_correct_adder() {
+        let generated = adder(&mut StdRng::seed_from_u64(2), 16, 0);
+        let device = parse(&generated.input).unwrap();
+
+        let result = output(&device);
+        assert!(result.is_ok());
+        assert_eq!(generated.part1.unwrap(), result.unwrap().to_string());
+
+        let result = swapped_wires(&device);
+        assert!(result.is_ok());
+        assert_eq!("", result.unwrap())
+    }
+}
@@ -0,0 +1,479 @@
+pub mod generate;
+
+use anyhow::{bail, Context, Result};
+use aoc_common::{ParseError, Solution};
+use itertools::Itertools;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::{collections::BTreeSet, fmt::Display};
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Device;
+
+    fn parse(data: &str) -> Result<Self::Input> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display> {
+        output(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display> {
+        swapped_wires(input)
+    }
+}
+
+pub fn calculate_output(filename: &str) -> Result<u64> {
+    output(&Day24::parse_file(filename)?)
+}
+
+pub fn find_swapped_wires(filename: &str) -> Result<String> {
+    swapped_wires(&Day24::parse_file(filename)?)
+}
+
+fn output(device: &Device) -> Result<u64> {
+    let mut device = device.clone();
+
+    if !device.simulate() {
+        bail!("The gates form a loop or miss an input");
+    }
+
+    Ok(device.output())
+}
+
+fn swapped_wires(device: &Device) -> Result<String> {
+    let wires = device.find_misplaced_outputs();
+    let pairs = device
+        .find_swapped_pairs(&wires)?
+        .context("No valid swaps found for the misplaced outputs")?;
+
+    Ok(pairs.iter().flat_map(|(a, b)| [a, b]).sorted().join(","))
+}
+
+pub fn parse(data: &str) -> Result<Device> {
+    let mut inputs = Vec::new();
+    let mut outputs = Vec::new();
+    let mut gates = Vec::new();
+
+    for (index, line) in data.lines().enumerate() {
+        if line.is_empty() {
+            continue;
+        }
+
+        if let Some((name, value)) = line.split_once(':') {
+            inputs.push(Input {
+                name: name.to_string(),
+                value: value.trim().parse()?,
+            });
+        } else {
+            let (gate, output) = line
+                .split_once("->")
+                .ok_or_else(|| ParseError::in_line(index, line, line.len(), "'->'"))?;
+            let gate_values: Vec<&str> = gate.split_whitespace().collect();
+            let [a, op, b] = gate_values[..] else {
+                return Err(ParseError::in_line(index, line, gate.len(), "two gate inputs").into());
+            };
+
+            let output = Output::new(output.trim());
+            let gate = Gate {
+                inputs: (Output::new(a), Output::new(b)),
+                output: output.clone(),
+                op: GateOp::try_from(op).map_err(|err| err.at_field(index, line, op))?,
+            };
+
+            gates.push(gate);
+            outputs.push(output);
+        }
+    }
+
+    Ok(Device {
+        inputs,
+        outputs,
+        gates,
+    })
+}
+
+#[derive(Debug, Clone)]
+pub struct Device {
+    inputs: Vec<Input>,
+    outputs: Vec<Output>,
+    gates: Vec<Gate>,
+}
+
+impl Device {
+    fn initialize(&mut self) {
+        for input in &self.inputs {
+            for gate in &mut self.gates {
+                if let Some(gate_input) = gate.get_input(&input.name) {
+                    gate_input.value = Some(input.value);
+                }
+            }
+        }
+    }
+
+    fn finished(&self) -> bool {
+        self.outputs.iter().all(|output| output.value.is_some())
+    }
+
+    fn calculate(&mut self) {
+        let mut changed_outputs = Vec::new();
+        for gate in self.gates.iter_mut().filter(|gate| {
+            gate.inputs.0.value.is_some()
+                && gate.inputs.1.value.is_some()
+                && gate.output.value.is_none()
+        }) {
+            let output = Some(
+                gate.op
+                    .calculate(gate.inputs.0.value.unwrap(), gate.inputs.1.value.unwrap()),
+            );
+
+            gate.output.value = output;
+            changed_outputs.push(gate.output.clone());
+        }
+
+        for output in changed_outputs {
+            if let Some(device_output) = self.outputs.iter_mut().find(|o| o.name == output.name) {
+                device_output.value = output.value;
+            }
+
+            for input in self
+                .gates
+                .iter_mut()
+                .filter_map(|gate| gate.get_input(&output.name))
+            {
+                input.value = output.value;
+            }
+        }
+    }
+
+    // like `calculate` but stops if no gate can be evaluated anymore,
+    // which happens if swapped outputs created a loop in the circuit
+    fn simulate(&mut self) -> bool {
+        self.initialize();
+
+        while !self.finished() {
+            let missing = self.missing_outputs();
+            self.calculate();
+            if self.missing_outputs() == missing {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    fn missing_outputs(&self) -> usize {
+        self.outputs
+            .iter()
+            .filter(|output| output.value.is_none())
+            .count()
+    }
+
+    fn set_input(&mut self, prefix: char, value: u64) -> Result<()> {
+        for input in &mut self.inputs {
+            if let Some(index) = input.name.strip_prefix(prefix) {
+                let index: u32 = index
+                    .parse()
+                    .with_context(|| format!("Invalid input index of {}", input.name))?;
+                input.value = value.checked_shr(index).map_or(0, |value| value & 1) as u8;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn input_bits(&self) -> u32 {
+        self.inputs
+            .iter()
+            .filter(|input| input.name.starts_with('x'))
+            .count() as u32
+    }
+
+    fn swap_outputs(&mut self, a: &str, b: &str) {
+        for gate in &mut self.gates {
+            if gate.output.name == a {
+                gate.output.name = b.to_string();
+            } else if gate.output.name == b {
+                gate.output.name = a.to_string();
+            }
+        }
+    }
+
+    // simulates additions that exercise every bit on its own, with and
+    // without a carry, a carry rippling through all bits and some random
+    // numbers, a correct adder has to calculate x + y for all of them
+    fn verify_adder(&self) -> Result<bool> {
+        let bits = self.input_bits();
+        let mask = u64::MAX.checked_shr(64 - bits).unwrap_or(0);
+
+        let single_bits = (0..bits).flat_map(|i| [(1 << i, 0), (0, 1 << i), (1 << i, 1 << i)]);
+        let carry_chain = [(mask, 1), (1, mask), (mask, mask)];
+        let mut rng = StdRng::seed_from_u64(24);
+        let random = (0..20).map(|_| (rng.gen::<u64>() & mask, rng.gen::<u64>() & mask));
+
+        for (x, y) in single_bits.chain(carry_chain).chain(random) {
+            let mut device = self.clone();
+            device.set_input('x', x)?;
+            device.set_input('y', y)?;
+            if !device.simulate() || u128::from(device.output()) != u128::from(x) + u128::from(y) {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+
+    // checks every gate against the structure of a ripple-carry adder:
+    //   x XOR y -> a, x AND y -> b, a XOR carry -> z, a AND carry -> c, b OR c -> carry
+    // only the first bit is a half adder and the last z is the final carry
+    fn find_misplaced_outputs(&self) -> BTreeSet<String> {
+        let last_output = self
+            .outputs
+            .iter()
+            .map(|output| output.name.as_str())
+            .filter(|name| name.starts_with('z'))
+            .max()
+            .unwrap_or_default();
+
+        let mut misplaced = BTreeSet::new();
+        for gate in &self.gates {
+            let output = gate.output.name.as_str();
+            let is_input = gate.inputs.0.is_device_input() && gate.inputs.1.is_device_input();
+            let is_first_bit = [&gate.inputs.0.name, &gate.inputs.1.name]
+                .iter()
+                .all(|name| *name == "x00" || *name == "y00");
+
+            let valid = match gate.op {
+                GateOp::Xor if is_input && is_first_bit => output == "z00",
+                GateOp::Xor if is_input => self.feeds_into(output, GateOp::Xor),
+                GateOp::Xor => output.starts_with('z') && output != last_output,
+                GateOp::And if is_first_bit => !output.starts_with('z'),
+                GateOp::And => !output.starts_with('z') && self.feeds_into(output, GateOp::Or),
+                GateOp::Or => !output.starts_with('z') || output == last_output,
+            };
+
+            if !valid {
+                misplaced.insert(gate.output.name.clone());
+            }
+        }
+
+        misplaced
+    }
+
+    // tries every pairing of the misplaced outputs until the device works as an adder
+    fn find_swapped_pairs(
+        &self,
+        wires: &BTreeSet<String>,
+    ) -> Result<Option<Vec<(String, String)>>> {
+        let Some(first) = wires.first() else {
+            return Ok(self.verify_adder()?.then(Vec::new));
+        };
+
+        for second in wires.iter().skip(1) {
+            let mut remaining = wires.clone();
+            remaining.remove(first);
+            remaining.remove(second);
+
+            let mut device = self.clone();
+            device.swap_outputs(first, second);
+            if let Some(mut pairs) = device.find_swapped_pairs(&remaining)? {
+                pairs.push((first.clone(), second.clone()));
+                return Ok(Some(pairs));
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn feeds_into(&self, wire: &str, op: GateOp) -> bool {
+        self.gates
+            .iter()
+            .any(|gate| gate.op == op && (gate.inputs.0.name == wire || gate.inputs.1.name == wire))
+    }
+
+    fn output(&self) -> u64 {
+        let outputs = self
+            .outputs
+            .iter()
+            .filter(|output| output.name.starts_with('z'))
+            .sorted_by_key(|output| output.name.clone());
+
+        let mut output = 0;
+        for (i, v) in outputs.enumerate() {
+            output += (v.value.unwrap() as u64) << i;
+        }
+
+        output
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Input {
+    name: String,
+    value: u8,
+}
+
+#[derive(Debug, Clone)]
+struct Output {
+    name: String,
+    value: Option<u8>,
+}
+
+impl Output {
+    fn new(name: &str) -> Output {
+        Output {
+            name: name.to_string(),
+            value: None,
+        }
+    }
+
+    fn is_device_input(&self) -> bool {
+        self.name.starts_with('x') || self.name.starts_with('y')
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Gate {
+    inputs: (Output, Output),
+    output: Output,
+    op: GateOp,
+}
+
+impl Gate {
+    fn get_input(&mut self, name: &str) -> Option<&mut Output> {
+        if self.inputs.0.name == name {
+            Some(&mut self.inputs.0)
+        } else if self.inputs.1.name == name {
+            Some(&mut self.inputs.1)
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum GateOp {
+    And,
+    Or,
+    Xor,
+}
+
+impl TryFrom<&str> for GateOp {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, ParseError> {
+        match value {
+            "AND" => Ok(GateOp::And),
+            "OR" => Ok(GateOp::Or),
+            "XOR" => Ok(GateOp::Xor),
+            _ => Err(ParseError::new(value.chars().next(), "AND, OR or XOR")),
+        }
+    }
+}
+
+impl GateOp {
+    fn calculate(&self, a: u8, b: u8) -> u8 {
+        match self {
+            GateOp::And => a & b,
+            GateOp::Or => a | b,
+            GateOp::Xor => a ^ b,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::answers::expected;
+
+    #[test]
+    fn test_smallir_a() {
+        let result = calculate_output("input_smaller.txt");
+        assert!(result.is_ok());
+        assert_eq!(
+            expected(1, "input_smaller.txt"),
+            result.unwrap().to_string()
+        )
+    }
+
+    #[test]
+    fn test_small_a() {
+        let result = calculate_output("input_small.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(1, "input_small.txt"), result.unwrap().to_string())
+    }
+
+    #[test]
+    fn test_input_a() {
+        let result = calculate_output("input.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(1, "input.txt"), result.unwrap().to_string())
+    }
+
+    #[test]
+    fn test_small_b() {
+        let result = find_swapped_wires("input_adder.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(2, "input_adder.txt"), result.unwrap().to_string())
+    }
+
+    #[test]
+    fn test_input_b() {
+        let result = find_swapped_wires("input.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(2, "input.txt"), result.unwrap().to_string())
+    }
+
+    #[test]
+    fn test_verify_small_adder() {
+        let mut device = Day24::parse_file("input_adder.txt").unwrap();
+        assert!(!device.verify_adder().unwrap());
+
+        device.swap_outputs("qdr", "z02");
+        assert!(device.verify_adder().unwrap());
+    }
+
+    #[test]
+    fn test_verify_input_adder() {
+        let mut device = Day24::parse_file("input.txt").unwrap();
+        assert!(!device.verify_adder().unwrap());
+
+        let wires = device.find_misplaced_outputs();
+        let pairs = device.find_swapped_pairs(&wires).unwrap().unwrap();
+        assert_eq!(4, pairs.len());
+
+        for (a, b) in pairs {
+            device.swap_outputs(&a, &b);
+        }
+        assert!(device.verify_adder().unwrap());
+    }
+
+    #[test]
+    fn test_first_bit() {
+        let device = Day24::parse(
+            "x00: 1\ny00: 1\n\nx00 XOR y00 -> z00\nx00 AND y00 -> w00\nw00 AND y00 -> abc\n",
+        );
+        let misplaced = device.unwrap().find_misplaced_outputs();
+        assert_eq!(BTreeSet::from(["abc".to_string()]), misplaced);
+    }
+
+    #[test]
+    fn test_gate_loop() {
+        let device = Day24::parse("x00: 1\ny00: 1\n\nx00 XOR y00 -> z00\nz00 AND y00 -> z00\n");
+        assert!(output(&device.unwrap()).is_err());
+
+        let device = Day24::parse("x00: 1\ny00: 1\n\nz01 XOR y00 -> z00\nz00 AND y00 -> z01\n");
+        assert!(output(&device.unwrap()).is_err());
+
+        let device = Day24::parse("z01 XOR y00 -> z00\nz00 AND y00 -> z01\n");
+        assert!(output(&device.unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_set_input() {
+        let mut device = Day24::parse("x00: 0\nx01: 0\nxab: 1\n\nx00 AND x01 -> z00\n").unwrap();
+        assert!(device.set_input('y', 3).is_ok());
+        assert!(device.set_input('x', 3).is_err());
+    }
+}
@@ -0,0 +1,44 @@
+use crate::Day25;
+use aoc_common::generate::{Generated, Generator};
+use rand::{rngs::StdRng, Rng};
+
+impl Generator for Day25 {
+    const SIZE: usize = 500;
+
+    /// `size` schematics, each one a lock or a key with random pin heights.
+    fn generate(rng: &mut StdRng, size: usize) -> Generated {
+        let mut locks = Vec::new();
+        let mut keys = Vec::new();
+        let mut schematics = Vec::new();
+
+        for _ in 0..size {
+            let heights: [usize; 5] = std::array::from_fn(|_| rng.gen_range(0..=5));
+            let is_lock = rng.gen_bool(0.5);
+
+            // a lock hangs from the top row, a key stands on the bottom row
+            let rows: Vec<String> = (0..7)
+                .map(|row| {
+                    let level = if is_lock { row } else { 6 - row };
+                    heights
+                        .iter()
+                        .map(|&height| if level <= height { '#' } else { '.' })
+                        .collect()
+                })
+                .collect();
+            schematics.push(rows.join("\n"));
+
+            match is_lock {
+                true => locks.push(heights),
+                false => keys.push(heights),
+            }
+        }
+
+        let fits = locks
+            .iter()
+            .flat_map(|lock| keys.iter().map(move |key| (lock, key)))
+            .filter(|(lock, key)| lock.iter().zip(key.iter()).all(|(l, k)| l + k <= 5))
+            .count();
+
+        Generated::new(schematics.join("\n\n")).with_part1(fits)
+    }
+}
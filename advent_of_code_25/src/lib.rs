@@ -0,0 +1,183 @@
+pub mod generate;
+
+use anyhow::{anyhow, Result};
+use aoc_common::{ParseError, Solution};
+use itertools::Itertools;
+use std::fmt::Display;
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = Elements;
+
+    fn parse(data: &str) -> Result<Self::Input> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display> {
+        lock_key_pairs(input)
+    }
+
+    // the last day only has a single puzzle
+    fn part2(_input: &Self::Input) -> Result<impl Display> {
+        Err::<usize, _>(anyhow!("Day 25 has no second part"))
+    }
+}
+
+pub fn get_lock_key_pairs(filename: &str) -> Result<usize> {
+    lock_key_pairs(&Day25::parse_file(filename)?)
+}
+
+fn lock_key_pairs(elements: &Elements) -> Result<usize> {
+    let mut fits = 0;
+    for key in &elements.keys {
+        for lock in &elements.locks {
+            if fit_key_lock(key, lock) {
+                fits += 1;
+            }
+        }
+    }
+
+    Ok(fits)
+}
+
+pub fn parse(data: &str) -> Result<Elements> {
+    let mut locks = Vec::new();
+    let mut keys = Vec::new();
+
+    // the schematics are separated by blank lines
+    let blocks = data
+        .lines()
+        .enumerate()
+        .chunk_by(|(_, line)| line.is_empty());
+    for (_, block) in blocks.into_iter().filter(|(blank, _)| !blank) {
+        let (indices, lines): (Vec<usize>, Vec<&str>) = block.unzip();
+        match parse_element(indices[0], lines)? {
+            Element::Lock(lock) => {
+                locks.push(lock);
+            }
+            Element::Key(key) => {
+                keys.push(key);
+            }
+        }
+    }
+
+    Ok(Elements { locks, keys })
+}
+
+fn parse_element(first_line: usize, lines: Vec<&str>) -> Result<Element, ParseError> {
+    if lines[0] == "#####" {
+        Ok(Element::Lock(Lock(parse_data(first_line, lines)?)))
+    } else if lines.get(6) == Some(&"#####") {
+        Ok(Element::Key(Key(parse_data(first_line, lines)?)))
+    } else {
+        // a lock has its top row filled, a key its bottom row
+        let line = lines[0];
+        let offset = line.find(|c| c != '#').unwrap_or(line.len());
+        Err(ParseError::in_line(
+            first_line,
+            line,
+            offset,
+            "a lock or a key",
+        ))
+    }
+}
+
+fn parse_data(first_line: usize, lines: Vec<&str>) -> Result<[u8; 5], ParseError> {
+    // every row has to be five cells wide, or the heights can't be compared
+    for (index, line) in lines.iter().enumerate() {
+        if let Some((offset, _)) = line.char_indices().nth(5) {
+            return Err(ParseError::in_line(
+                first_line + index,
+                line,
+                offset,
+                "the end of the row",
+            ));
+        } else if line.chars().count() < 5 {
+            return Err(ParseError::in_line(
+                first_line + index,
+                line,
+                line.len(),
+                "five cells",
+            ));
+        }
+    }
+
+    let mut data = [0, 0, 0, 0, 0];
+    for line in lines.iter().skip(1).take(5) {
+        for (i, c) in line.chars().enumerate() {
+            if c == '#' {
+                data[i] += 1;
+            }
+        }
+    }
+    Ok(data)
+}
+
+fn fit_key_lock(key: &Key, lock: &Lock) -> bool {
+    for i in 0..5 {
+        if key.0[i] + lock.0[i] > 5 {
+            return false;
+        }
+    }
+    true
+}
+
+enum Element {
+    Lock(Lock),
+    Key(Key),
+}
+
+pub struct Elements {
+    locks: Vec<Lock>,
+    keys: Vec<Key>,
+}
+
+struct Lock([u8; 5]);
+
+struct Key([u8; 5]);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{answers::expected, solution::solve};
+
+    #[test]
+    fn test_small_a() {
+        let result = get_lock_key_pairs("input_small.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(1, "input_small.txt"), result.unwrap().to_string())
+    }
+
+    #[test]
+    fn test_untidy_input() {
+        let data = std::fs::read_to_string("input_small.txt").unwrap();
+        let untidy = format!(
+            "\u{feff}{}\r\n\r\n",
+            data.replace("\n\n", "\n\n\n").replace('\n', " \r\n")
+        );
+        let result = solve::<Day25>(1, &untidy);
+        assert!(result.is_ok());
+        assert_eq!(expected(1, "input_small.txt"), result.unwrap())
+    }
+
+    #[test]
+    fn test_invalid_width() {
+        let result = parse("#####\n.....#\n.....\n.....\n.....\n.....\n.....\n");
+        let err = result.err().unwrap().downcast::<ParseError>().unwrap();
+        assert_eq!(Some('#'), err.found());
+        assert_eq!((Some(2), Some(6)), (err.line(), err.column()));
+
+        let result = parse(".....\n#....\n#...\n#....\n#....\n#....\n#####\n");
+        let err = result.err().unwrap().downcast::<ParseError>().unwrap();
+        assert_eq!(None, err.found());
+        assert_eq!((Some(3), Some(5)), (err.line(), err.column()));
+    }
+
+    #[test]
+    fn test_input_a() {
+        let result = get_lock_key_pairs("input.txt");
+        assert!(result.is_ok());
+        assert_eq!(expected(1, "input.txt"), result.unwrap().to_string())
+    }
+}
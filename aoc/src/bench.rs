@@ -0,0 +1,190 @@
+use crate::{day_directory, days};
+use anyhow::{bail, Context, Result};
+use aoc_common::{
+    bench::{format_nanos, Options, Stats, Timings},
+    read_input,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    fs::{read_to_string, write},
+    path::Path,
+};
+
+/// The timings of a day as they are stored in the results file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Report {
+    pub day: u8,
+    #[serde(flatten)]
+    pub timings: Timings,
+}
+
+/// A phase of a day whose median got slower than allowed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Regression {
+    day: u8,
+    phase: &'static str,
+    baseline: u64,
+    median: u64,
+}
+
+/// Benchmarks `days` on their puzzle inputs and prints a summary table.
+///
+/// The results are written to `output` and compared against `baseline`,
+/// fails if the median of any phase got more than `threshold` percent slower.
+pub fn bench(
+    days: impl IntoIterator<Item = u8>,
+    options: Options,
+    output: Option<&Path>,
+    baseline: Option<&Path>,
+    threshold: f64,
+) -> Result<()> {
+    let baseline = baseline.map(load).transpose()?;
+    let mut reports = Vec::new();
+
+    println!(
+        "{:>3}  {:>10}  {:>10}  {:>10}",
+        "Day", "Parse", "Part 1", "Part 2"
+    );
+    for day in days {
+        let path = day_directory(day).join("input.txt");
+        if !path.exists() {
+            println!("{day:>3}  no puzzle input");
+            continue;
+        }
+
+        let data = read_input(&path.to_string_lossy())?;
+        let timings = days::bench(day, &data, options)
+            .with_context(|| format!("Failed to benchmark day {day}"))?;
+        println!(
+            "{day:>3}  {:>10}  {:>10}  {:>10}",
+            format_nanos(timings.parse.median),
+            format_nanos(timings.part1.median),
+            timings
+                .part2
+                .map_or("-".to_string(), |stats| format_nanos(stats.median)),
+        );
+        reports.push(Report { day, timings });
+    }
+
+    if let Some(output) = output {
+        write(output, serde_json::to_string_pretty(&reports)?)
+            .with_context(|| format!("Failed to write results to {}", output.display()))?;
+    }
+
+    if let Some(baseline) = baseline {
+        let regressions = regressions(&baseline, &reports, threshold);
+        for regression in &regressions {
+            println!(
+                "Day {:2}, {}: {} -> {}",
+                regression.day,
+                regression.phase,
+                format_nanos(regression.baseline),
+                format_nanos(regression.median)
+            );
+        }
+        if !regressions.is_empty() {
+            bail!(
+                "{} phases are more than {threshold}% slower than the baseline",
+                regressions.len()
+            );
+        }
+    }
+
+    Ok(())
+}
+
+fn load(path: &Path) -> Result<Vec<Report>> {
+    let data = read_to_string(path)
+        .with_context(|| format!("Failed to read baseline {}", path.display()))?;
+    serde_json::from_str(&data).with_context(|| format!("Invalid baseline {}", path.display()))
+}
+
+/// The phases of `reports` whose median is more than `threshold` percent above
+/// the baseline, days and phases missing in the baseline are skipped.
+fn regressions(baseline: &[Report], reports: &[Report], threshold: f64) -> Vec<Regression> {
+    let mut regressions = Vec::new();
+
+    for report in reports {
+        let Some(base) = baseline.iter().find(|base| base.day == report.day) else {
+            continue;
+        };
+
+        let phases: [(&str, Option<Stats>, Option<Stats>); 3] = [
+            (
+                "parse",
+                Some(base.timings.parse),
+                Some(report.timings.parse),
+            ),
+            (
+                "part 1",
+                Some(base.timings.part1),
+                Some(report.timings.part1),
+            ),
+            ("part 2", base.timings.part2, report.timings.part2),
+        ];
+        for (phase, base, stats) in phases {
+            let (Some(base), Some(stats)) = (base, stats) else {
+                continue;
+            };
+            if stats.median as f64 > base.median as f64 * (1.0 + threshold / 100.0) {
+                regressions.push(Regression {
+                    day: report.day,
+                    phase,
+                    baseline: base.median,
+                    median: stats.median,
+                });
+            }
+        }
+    }
+
+    regressions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(day: u8, parse: u64, part1: u64, part2: Option<u64>) -> Report {
+        let stats = |median| Stats {
+            min: median,
+            median,
+            mean: median,
+        };
+        Report {
+            day,
+            timings: Timings {
+                parse: stats(parse),
+                part1: stats(part1),
+                part2: part2.map(stats),
+            },
+        }
+    }
+
+    #[test]
+    fn test_regressions() {
+        let baseline = [report(1, 100, 100, Some(100)), report(2, 100, 100, None)];
+        let reports = [
+            report(1, 105, 130, Some(100)),
+            report(2, 100, 100, Some(500)),
+            report(3, 900, 900, None),
+        ];
+
+        let result = regressions(&baseline, &reports, 10.0);
+        assert_eq!(
+            vec![Regression {
+                day: 1,
+                phase: "part 1",
+                baseline: 100,
+                median: 130
+            }],
+            result
+        );
+    }
+
+    #[test]
+    fn test_report_json() {
+        let report = report(25, 1, 2, None);
+        let json = serde_json::to_string(&report).unwrap();
+        assert_eq!(report, serde_json::from_str(&json).unwrap());
+    }
+}
@@ -0,0 +1,423 @@
+use anyhow::{bail, Context, Result};
+use serde::Deserialize;
+use std::{
+    env,
+    fmt::{self, Debug, Formatter},
+    fs::read_to_string,
+    path::Path,
+    time::Duration,
+};
+
+/// The website of the puzzles.
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+pub const YEAR: u16 = 2024;
+
+/// The website asks automated tools to say where they come from.
+const USER_AGENT: &str = "github.com/JulienKulbe/advent_of_code_2024 aoc runner";
+
+/// The settings of the runner, the `aoc.toml` at the root of the workspace.
+///
+/// The session token is the `session` cookie of a logged in browser, keep the
+/// file out of version control. `AOC_SESSION` and `AOC_URL` override it.
+///
+/// ```toml
+/// session = "53616c7465645f5f..."
+/// ```
+#[derive(Default, Deserialize)]
+pub struct Config {
+    session: Option<String>,
+    url: Option<String>,
+}
+
+// the session token is as good as the password of the account
+impl Debug for Config {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let session = self.session.as_ref().map(|_| "<redacted>");
+        f.debug_struct("Config")
+            .field("session", &session)
+            .field("url", &self.url)
+            .finish()
+    }
+}
+
+impl Config {
+    pub const FILENAME: &str = "aoc.toml";
+
+    /// Loads the settings, a missing file has no settings.
+    pub fn load(path: impl AsRef<Path>) -> Result<Config> {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Ok(Config::default());
+        }
+
+        let data = read_to_string(path)
+            .with_context(|| format!("Failed to read config file {}", path.display()))?;
+        Config::parse(&data).with_context(|| format!("Invalid config file {}", path.display()))
+    }
+
+    pub fn parse(data: &str) -> Result<Config> {
+        Ok(toml::from_str(data)?)
+    }
+
+    /// The session token, the environment takes precedence over the file.
+    pub fn session(&self) -> Result<String> {
+        let session = env::var("AOC_SESSION")
+            .ok()
+            .or_else(|| self.session.clone());
+        match session.as_deref().map(str::trim) {
+            Some(session) if !session.is_empty() => Ok(session.to_string()),
+            _ => bail!(
+                "No session token, set AOC_SESSION or `session` in {}",
+                Config::FILENAME
+            ),
+        }
+    }
+
+    /// The website to talk to, [`BASE_URL`] unless it is overridden.
+    pub fn url(&self) -> String {
+        env::var("AOC_URL")
+            .ok()
+            .or_else(|| self.url.clone())
+            .unwrap_or_else(|| BASE_URL.to_string())
+    }
+}
+
+/// The status code and body of an HTTP response.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// The HTTP requests the client makes, error statuses are responses too.
+pub trait Http {
+    fn get(&self, url: &str, session: &str) -> Result<Response>;
+
+    /// Posts `form` URL-encoded.
+    fn post(&self, url: &str, session: &str, form: &[(&str, &str)]) -> Result<Response>;
+}
+
+/// [`Http`] over blocking [ureq](https://docs.rs/ureq) requests.
+pub struct Ureq {
+    agent: ureq::Agent,
+}
+
+impl Default for Ureq {
+    fn default() -> Ureq {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(USER_AGENT)
+            .timeout(Duration::from_secs(30))
+            .build();
+        Ureq { agent }
+    }
+}
+
+impl Http for Ureq {
+    fn get(&self, url: &str, session: &str) -> Result<Response> {
+        let request = self
+            .agent
+            .get(url)
+            .set("Cookie", &format!("session={session}"));
+        response(request.call(), url)
+    }
+
+    fn post(&self, url: &str, session: &str, form: &[(&str, &str)]) -> Result<Response> {
+        let request = self
+            .agent
+            .post(url)
+            .set("Cookie", &format!("session={session}"));
+        response(request.send_form(form), url)
+    }
+}
+
+fn response(result: Result<ureq::Response, ureq::Error>, url: &str) -> Result<Response> {
+    let response = match result {
+        Ok(response) => response,
+        Err(ureq::Error::Status(_, response)) => response,
+        Err(err) => return Err(err).context("Request failed"),
+    };
+
+    let status = response.status();
+    let body = response
+        .into_string()
+        .with_context(|| format!("Failed to read the response of {url}"))?;
+    Ok(Response { status, body })
+}
+
+/// Talks to the puzzle website as the user of a session.
+pub struct Client<H: Http = Ureq> {
+    http: H,
+    url: String,
+    session: String,
+}
+
+impl<H: Http> Client<H> {
+    pub fn new(http: H, url: &str, session: &str) -> Client<H> {
+        Client {
+            http,
+            url: url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+        }
+    }
+
+    /// The URL of the puzzle of `day`, the other pages of a day are below it.
+    pub fn day_url(&self, day: u8) -> String {
+        format!("{}/{YEAR}/day/{day}", self.url)
+    }
+
+    /// Downloads the puzzle input of `day`.
+    pub fn input(&self, day: u8) -> Result<String> {
+        let url = format!("{}/input", self.day_url(day));
+        let response = self.http.get(&url, &self.session)?;
+        match response.status {
+            200 => Ok(response.body),
+            404 => bail!("The puzzle of day {day} is not unlocked yet"),
+            400 | 500 => bail!(
+                "The input of day {day} was refused with status {}, is the session token expired?",
+                response.status
+            ),
+            status => bail!("Downloading the input of day {day} failed with status {status}"),
+        }
+    }
+
+    /// Posts `answer` to `part` of `day` and returns the page that tells
+    /// whether it was right.
+    pub fn answer(&self, day: u8, part: u8, answer: &str) -> Result<String> {
+        let url = format!("{}/answer", self.day_url(day));
+        let level = part.to_string();
+        let form = [("level", level.as_str()), ("answer", answer)];
+
+        let response = self.http.post(&url, &self.session, &form)?;
+        match response.status {
+            200 => Ok(response.body),
+            404 => bail!("The puzzle of day {day} is not unlocked yet"),
+            400 | 500 => bail!(
+                "The answer to day {day} was refused with status {}, is the session token expired?",
+                response.status
+            ),
+            status => bail!("Submitting the answer to day {day} failed with status {status}"),
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod mock {
+    use std::{
+        collections::HashMap,
+        io::{BufRead, BufReader, ErrorKind, Write},
+        net::{TcpListener, TcpStream},
+        thread::{self, JoinHandle},
+        time::{Duration, Instant},
+    };
+
+    /// A request the mock server received.
+    #[derive(Debug, Clone)]
+    pub struct Request {
+        pub method: String,
+        pub path: String,
+        /// The headers with lowercase names.
+        pub headers: HashMap<String, String>,
+        pub body: String,
+    }
+
+    /// A local HTTP server that answers the requests one by one with canned
+    /// responses and then stops.
+    pub struct MockServer {
+        pub url: String,
+        handle: JoinHandle<Vec<Request>>,
+    }
+
+    impl MockServer {
+        pub fn start(responses: Vec<(u16, &str)>) -> MockServer {
+            MockServer::start_with_timeout(responses, Duration::from_secs(5))
+        }
+
+        /// Like [`MockServer::start`] but gives up if a request doesn't come
+        /// within `timeout`, [`MockServer::requests`] fails then.
+        pub fn start_with_timeout(responses: Vec<(u16, &str)>, timeout: Duration) -> MockServer {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            listener.set_nonblocking(true).unwrap();
+            let url = format!("http://{}", listener.local_addr().unwrap());
+            let responses: Vec<(u16, String)> = responses
+                .into_iter()
+                .map(|(status, body)| (status, body.to_string()))
+                .collect();
+
+            let handle = thread::spawn(move || {
+                let mut requests = Vec::new();
+                let count = responses.len();
+                for (status, body) in responses {
+                    let Some(mut stream) = accept(&listener, timeout) else {
+                        panic!("Only {} of {count} requests were made", requests.len());
+                    };
+                    stream.set_read_timeout(Some(timeout)).unwrap();
+                    requests.push(read_request(&mut BufReader::new(&stream)));
+
+                    let response = format!(
+                        "HTTP/1.1 {status} Mock\r\nContent-Type: text/plain\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                        body.len()
+                    );
+                    stream.write_all(response.as_bytes()).unwrap();
+                }
+                requests
+            });
+
+            MockServer { url, handle }
+        }
+
+        /// Waits until every response was sent and returns the requests, panics
+        /// if a request didn't come.
+        pub fn requests(self) -> Vec<Request> {
+            self.handle
+                .join()
+                .unwrap_or_else(|_| panic!("The mock server didn't get every request"))
+        }
+    }
+
+    // the next connection, `None` if none comes within `timeout`
+    fn accept(listener: &TcpListener, timeout: Duration) -> Option<TcpStream> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            match listener.accept() {
+                Ok((stream, _)) => {
+                    stream.set_nonblocking(false).unwrap();
+                    return Some(stream);
+                }
+                Err(err) if err.kind() == ErrorKind::WouldBlock => {
+                    if Instant::now() >= deadline {
+                        return None;
+                    }
+                    thread::sleep(Duration::from_millis(5));
+                }
+                Err(err) => panic!("Failed to accept a request: {err}"),
+            }
+        }
+    }
+
+    fn read_request(reader: &mut impl BufRead) -> Request {
+        let mut line = String::new();
+        reader.read_line(&mut line).unwrap();
+        let mut parts = line.split_whitespace();
+        let method = parts.next().unwrap_or_default().to_string();
+        let path = parts.next().unwrap_or_default().to_string();
+
+        let mut headers = HashMap::new();
+        loop {
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            match line.trim_end().split_once(':') {
+                Some((name, value)) => {
+                    headers.insert(name.to_lowercase(), value.trim().to_string());
+                }
+                None => break,
+            }
+        }
+
+        let length = headers
+            .get("content-length")
+            .map_or(0, |length| length.parse().unwrap());
+        let mut body = vec![0; length];
+        reader.read_exact(&mut body).unwrap();
+
+        Request {
+            method,
+            path,
+            headers,
+            body: String::from_utf8(body).unwrap(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{mock::MockServer, *};
+
+    #[test]
+    fn test_config() {
+        let config = Config::parse("session = \"abc\"\nurl = \"http://localhost:8080\"").unwrap();
+        assert_eq!(Some("abc"), config.session.as_deref());
+        assert_eq!(Some("http://localhost:8080"), config.url.as_deref());
+
+        let debug = format!("{config:?}");
+        assert!(!debug.contains("abc"));
+        assert!(debug.contains("localhost:8080"));
+
+        let config = Config::load("does_not_exist.toml").unwrap();
+        assert!(config.session.is_none());
+        assert!(Config::parse("session = 1").is_err());
+    }
+
+    #[test]
+    fn test_input() {
+        let server = MockServer::start(vec![(200, "1 2\n3 4\n")]);
+        let client = Client::new(Ureq::default(), &format!("{}/", server.url), "secret");
+
+        let result = client.input(7);
+        assert!(result.is_ok());
+        assert_eq!("1 2\n3 4\n", result.unwrap());
+
+        let requests = server.requests();
+        assert_eq!(1, requests.len());
+        assert_eq!("GET", requests[0].method);
+        assert_eq!("/2024/day/7/input", requests[0].path);
+        assert_eq!(
+            Some("session=secret"),
+            requests[0].headers.get("cookie").map(String::as_str)
+        );
+        assert_eq!(
+            Some(USER_AGENT),
+            requests[0].headers.get("user-agent").map(String::as_str)
+        );
+    }
+
+    #[test]
+    fn test_answer() {
+        let server = MockServer::start(vec![(
+            200,
+            "<article><p>That's the right answer!</p></article>",
+        )]);
+        let client = Client::new(Ureq::default(), &server.url, "secret");
+
+        let result = client.answer(3, 2, "co,de ka");
+        assert!(result.is_ok());
+        assert!(result.unwrap().contains("right answer"));
+
+        let requests = server.requests();
+        assert_eq!("POST", requests[0].method);
+        assert_eq!("/2024/day/3/answer", requests[0].path);
+        assert_eq!("level=2&answer=co%2Cde+ka", requests[0].body);
+        assert_eq!(
+            Some("application/x-www-form-urlencoded"),
+            requests[0].headers.get("content-type").map(String::as_str)
+        );
+    }
+
+    #[test]
+    fn test_input_errors() {
+        let server = MockServer::start(vec![
+            (404, "Not Found"),
+            (400, "Puzzle inputs differ by user."),
+        ]);
+        let client = Client::new(Ureq::default(), &server.url, "secret");
+
+        let err = client.input(25).unwrap_err();
+        assert!(err.to_string().contains("not unlocked"));
+        let err = client.input(1).unwrap_err();
+        assert!(err.to_string().contains("session token"));
+        assert_eq!(2, server.requests().len());
+
+        // nothing listens on the port of the stopped server
+        assert!(client.input(1).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "didn't get every request")]
+    fn test_missing_request() {
+        let responses = vec![(200, "1 2\n"), (200, "3 4\n")];
+        let server = MockServer::start_with_timeout(responses, Duration::from_millis(200));
+        let client = Client::new(Ureq::default(), &server.url, "secret");
+        assert!(client.input(1).is_ok());
+        server.requests();
+    }
+}
@@ -0,0 +1,223 @@
+use anyhow::{bail, Result};
+use aoc_common::{
+    answer::Answer,
+    bench::{self, Options, Timings},
+    generate::{self, Generated, Generator},
+    record::{record as recorded, Recorder},
+    render::{snapshot, Canvas},
+    solution, Solution,
+};
+
+pub const DAYS: u8 = 25;
+
+/// The parts of the puzzle of a day.
+pub fn parts(day: u8) -> Vec<u8> {
+    if day == DAYS {
+        vec![1]
+    } else {
+        vec![1, 2]
+    }
+}
+
+/// Calls the generic function `f` with the [`aoc_common::Solution`] of `day`,
+/// fails if there is no solution for the day.
+macro_rules! dispatch {
+    ($day:expr, $($f:ident)::+, ($($arg:expr),*)) => {
+        match $day {
+            1 => $($f)::+::<advent_of_code_01::Day01>($($arg),*),
+            2 => $($f)::+::<advent_of_code_02::Day02>($($arg),*),
+            3 => $($f)::+::<advent_of_code_03::Day03>($($arg),*),
+            4 => $($f)::+::<advent_of_code_04::Day04>($($arg),*),
+            5 => $($f)::+::<advent_of_code_05::Day05>($($arg),*),
+            6 => $($f)::+::<advent_of_code_06::Day06>($($arg),*),
+            7 => $($f)::+::<advent_of_code_07::Day07>($($arg),*),
+            8 => $($f)::+::<advent_of_code_08::Day08>($($arg),*),
+            9 => $($f)::+::<advent_of_code_09::Day09>($($arg),*),
+            10 => $($f)::+::<advent_of_code_10::Day10>($($arg),*),
+            11 => $($f)::+::<advent_of_code_11::Day11>($($arg),*),
+            12 => $($f)::+::<advent_of_code_12::Day12>($($arg),*),
+            13 => $($f)::+::<advent_of_code_13::Day13>($($arg),*),
+            14 => $($f)::+::<advent_of_code_14::Day14>($($arg),*),
+            15 => $($f)::+::<advent_of_code_15::Day15>($($arg),*),
+            16 => $($f)::+::<advent_of_code_16::Day16>($($arg),*),
+            17 => $($f)::+::<advent_of_code_17::Day17>($($arg),*),
+            18 => $($f)::+::<advent_of_code_18::Day18>($($arg),*),
+            19 => $($f)::+::<advent_of_code_19::Day19>($($arg),*),
+            20 => $($f)::+::<advent_of_code_20::Day20>($($arg),*),
+            21 => $($f)::+::<advent_of_code_21::Day21>($($arg),*),
+            22 => $($f)::+::<advent_of_code_22::Day22>($($arg),*),
+            23 => $($f)::+::<advent_of_code_23::Day23>($($arg),*),
+            24 => $($f)::+::<advent_of_code_24::Day24>($($arg),*),
+            25 => $($f)::+::<advent_of_code_25::Day25>($($arg),*),
+            day => bail!("No solution for day {day}"),
+        }
+    };
+}
+
+/// Solves one part of a day for the puzzle input `data`.
+pub fn solve(day: u8, part: u8, data: &str) -> Result<String> {
+    dispatch!(day, solution::solve, (part, data))
+}
+
+/// Solves one part of a day for the puzzle input `data` and records how.
+pub fn answer(day: u8, part: u8, data: &str) -> Result<Answer> {
+    dispatch!(day, Answer::solve, (day, part, data))
+}
+
+/// Times parsing and the parts of a day for the puzzle input `data`.
+pub fn bench(day: u8, data: &str, options: Options) -> Result<Timings> {
+    dispatch!(day, bench::bench, (data, options, day != DAYS))
+}
+
+/// Generates an input of a day for `seed`, `size` defaults to the size of the
+/// actual puzzle input. With `check` the planted answers are verified as well.
+pub fn generate(day: u8, seed: u64, size: Option<usize>, check: bool) -> Result<Generated> {
+    dispatch!(day, generated, (seed, size, check))
+}
+
+/// Draws the state of a day for the puzzle input `data`, only some days
+/// know how to draw themselves.
+pub fn render(day: u8, data: &str) -> Result<Canvas> {
+    match day {
+        6 => snapshot::<advent_of_code_06::Day06>(data),
+        12 => snapshot::<advent_of_code_12::Day12>(data),
+        14 => snapshot::<advent_of_code_14::Day14>(data),
+        15 => snapshot::<advent_of_code_15::Day15>(data),
+        16 => snapshot::<advent_of_code_16::Day16>(data),
+        18 => snapshot::<advent_of_code_18::Day18>(data),
+        20 => snapshot::<advent_of_code_20::Day20>(data),
+        day => bail!("Day {day} can't be rendered"),
+    }
+}
+
+/// Runs the simulation of a day for the puzzle input `data` and reports every
+/// step to `recorder`, only some days are step-based simulations.
+pub fn record(day: u8, data: &str, recorder: &mut Recorder) -> Result<()> {
+    match day {
+        6 => recorded::<advent_of_code_06::Day06>(data, recorder),
+        9 => recorded::<advent_of_code_09::Day09>(data, recorder),
+        14 => recorded::<advent_of_code_14::Day14>(data, recorder),
+        15 => recorded::<advent_of_code_15::Day15>(data, recorder),
+        day => bail!("Day {day} can't be recorded"),
+    }
+}
+
+fn generated<S: Solution + Generator>(
+    seed: u64,
+    size: Option<usize>,
+    check: bool,
+) -> Result<Generated> {
+    let size = size.unwrap_or(S::SIZE);
+    match check {
+        true => generate::check::<S>(seed, size),
+        false => Ok(generate::generate::<S>(seed, size)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+
+    #[test]
+    fn test_unknown_day() {
+        assert!(solve(26, 1, EXAMPLE).is_err());
+        assert!(solve(1, 3, EXAMPLE).is_err());
+    }
+
+    #[test]
+    fn test_answer_day() {
+        let result = answer(1, 2, EXAMPLE);
+        assert!(result.is_ok());
+
+        let answer = result.unwrap();
+        assert_eq!(
+            (1, 2, "31"),
+            (answer.day, answer.part, answer.answer.as_str())
+        );
+        assert_eq!(vec![1, 2], parts(1));
+        assert_eq!(vec![1], parts(DAYS));
+    }
+
+    #[test]
+    fn test_solve_day() {
+        let result = solve(1, 1, EXAMPLE);
+        assert!(result.is_ok());
+        assert_eq!("11", result.unwrap())
+    }
+
+    #[test]
+    fn test_generate_day() {
+        let result = generate(1, 5, Some(10), true);
+        assert!(result.is_ok());
+        let generated = result.unwrap();
+        assert_eq!(10, generated.input.lines().count());
+        assert_eq!(
+            generated.part1.as_deref(),
+            solve(1, 1, &generated.input).ok().as_deref()
+        );
+        assert!(generate(26, 5, None, false).is_err());
+    }
+
+    #[test]
+    fn test_generated_days() {
+        // small inputs keep the tests fast, `None` is the size of the actual puzzle
+        let sizes = [
+            Some(100),
+            Some(100),
+            Some(300),
+            Some(40),
+            Some(50),
+            Some(40),
+            Some(100),
+            Some(30),
+            Some(100),
+            Some(30),
+            Some(3),
+            Some(30),
+            Some(100),
+            Some(200),
+            Some(20),
+            Some(41),
+            None,
+            None,
+            Some(100),
+            Some(61),
+            None,
+            Some(10),
+            Some(100),
+            Some(20),
+            None,
+        ];
+
+        for (day, size) in (1..=DAYS).zip(sizes) {
+            // the planted answers are checked, the other parts only have to be solved
+            let result = generate(day, 1, size, true);
+            assert!(result.is_ok(), "day {day}: {result:?}");
+            let generated = result.unwrap();
+            for part in parts(day) {
+                let result = solve(day, part, &generated.input);
+                assert!(result.is_ok(), "day {day}, part {part}: {result:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_render_day() {
+        let result = render(12, "AAB\nBAB\n");
+        assert!(result.is_ok());
+        assert_eq!("AAB\nBAB\n", result.unwrap().to_string());
+        assert!(render(1, EXAMPLE).is_err());
+    }
+
+    #[test]
+    fn test_record_day() {
+        let mut recorder = Recorder::new(2);
+        let result = record(6, ".#..\n...#\n.^..\n", &mut recorder);
+        assert!(result.is_ok());
+        assert_eq!(6, recorder.steps());
+        assert_eq!(4, recorder.frames().len());
+        assert!(record(1, EXAMPLE, &mut recorder).is_err());
+    }
+}
@@ -0,0 +1,150 @@
+use crate::{
+    client::{Client, Http},
+    day_directory,
+};
+use anyhow::{ensure, Context, Result};
+use std::{
+    fs::{rename, write},
+    path::{Path, PathBuf},
+};
+
+/// Where the puzzle input of a day came from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fetched {
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+/// Downloads the puzzle input of `days` into their directories. The client is
+/// only created if an input is missing, cached inputs don't need a session.
+pub fn fetch<H: Http>(
+    client: impl FnOnce() -> Result<Client<H>>,
+    days: impl IntoIterator<Item = u8>,
+) -> Result<()> {
+    let mut missing = Vec::new();
+    for day in days {
+        let path = day_directory(day).join("input.txt");
+        match path.exists() {
+            true => println!("Day {day}: cached at {}", path.display()),
+            false => missing.push(day),
+        }
+    }
+    if missing.is_empty() {
+        return Ok(());
+    }
+
+    let client = client()?;
+    for day in missing {
+        match fetch_input(&client, day, &day_directory(day))? {
+            Fetched::Cached(path) => println!("Day {day}: cached at {}", path.display()),
+            Fetched::Downloaded(path) => println!("Day {day}: downloaded to {}", path.display()),
+        }
+    }
+
+    Ok(())
+}
+
+/// Downloads the puzzle input of `day` to the `input.txt` in `directory`.
+/// An existing input is never downloaded again.
+pub fn fetch_input(client: &Client<impl Http>, day: u8, directory: &Path) -> Result<Fetched> {
+    let path = directory.join("input.txt");
+    if path.exists() {
+        return Ok(Fetched::Cached(path));
+    }
+
+    let input = client.input(day)?;
+    ensure!(!input.trim().is_empty(), "The input of day {day} is empty");
+
+    // an interrupted download must not end up in the cache
+    let partial = directory.join("input.txt.part");
+    write(&partial, &input)
+        .with_context(|| format!("Failed to write input to {}", partial.display()))?;
+    rename(&partial, &path)
+        .with_context(|| format!("Failed to write input to {}", path.display()))?;
+
+    Ok(Fetched::Downloaded(path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::{mock::MockServer, Response, Ureq};
+    use std::{cell::Cell, fs, fs::read_to_string, rc::Rc};
+
+    // serves the same input for every request and counts the requests
+    struct Fake {
+        requests: Rc<Cell<usize>>,
+    }
+
+    impl Http for Fake {
+        fn get(&self, _: &str, _: &str) -> Result<Response> {
+            self.requests.set(self.requests.get() + 1);
+            Ok(Response {
+                status: 200,
+                body: "3   4\n4   3\n".to_string(),
+            })
+        }
+
+        fn post(&self, _: &str, _: &str, _: &[(&str, &str)]) -> Result<Response> {
+            unreachable!("Fetching never posts")
+        }
+    }
+
+    fn directory(name: &str) -> PathBuf {
+        let directory = std::env::temp_dir().join(format!("aoc_{name}_{}", std::process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        directory
+    }
+
+    #[test]
+    fn test_cache() {
+        let directory = directory("cache");
+        let requests = Rc::new(Cell::new(0));
+        let fake = Fake {
+            requests: requests.clone(),
+        };
+        let client = Client::new(fake, "http://unused", "secret");
+
+        let path = directory.join("input.txt");
+        assert_eq!(
+            Some(Fetched::Downloaded(path.clone())),
+            fetch_input(&client, 1, &directory).ok()
+        );
+        assert_eq!(
+            Some(Fetched::Cached(path.clone())),
+            fetch_input(&client, 1, &directory).ok()
+        );
+        assert_eq!(1, requests.get());
+        assert_eq!("3   4\n4   3\n", read_to_string(&path).unwrap());
+
+        fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn test_cached_without_client() {
+        // the input of day 1 is checked in
+        let client = || -> Result<Client<Fake>> { anyhow::bail!("No session token") };
+        assert!(fetch(client, [1]).is_ok());
+    }
+
+    #[test]
+    fn test_fetch_from_server() {
+        let directory = directory("fetch");
+        let server = MockServer::start(vec![(500, "Internal Server Error"), (200, "0 1\n")]);
+        let client = Client::new(Ureq::default(), &server.url, "secret");
+
+        // a refused download leaves nothing behind
+        assert!(fetch_input(&client, 2, &directory).is_err());
+        assert_eq!(0, fs::read_dir(&directory).unwrap().count());
+
+        let result = fetch_input(&client, 2, &directory);
+        assert!(matches!(result, Ok(Fetched::Downloaded(_))));
+        assert_eq!(
+            "0 1\n",
+            read_to_string(directory.join("input.txt")).unwrap()
+        );
+        assert_eq!(2, server.requests().len());
+
+        fs::remove_dir_all(directory).unwrap();
+    }
+}
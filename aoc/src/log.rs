@@ -0,0 +1,71 @@
+use std::io;
+use tracing::level_filters::LevelFilter;
+
+/// The most detailed events shown for the number of `-v` flags, the
+/// solutions only emit debug and trace events so they are silent by default.
+pub fn level(verbose: u8) -> LevelFilter {
+    match verbose {
+        0 => LevelFilter::WARN,
+        1 => LevelFilter::DEBUG,
+        _ => LevelFilter::TRACE,
+    }
+}
+
+/// Prints the events of the solutions to stderr, stdout is kept for the answers.
+pub fn init(verbose: u8) {
+    tracing_subscriber::fmt()
+        .with_max_level(level(verbose))
+        .with_writer(io::stderr)
+        .without_time()
+        .init();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Clone, Default)]
+    struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+    impl io::Write for Buffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    /// Solves a part with the events up to the level of `verbose` collected.
+    fn solve_logged(verbose: u8, day: u8, part: u8, data: &str) -> String {
+        let buffer = Buffer::default();
+        let writer = buffer.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .with_max_level(level(verbose))
+            .with_writer(move || writer.clone())
+            .with_ansi(false)
+            .finish();
+
+        let result = tracing::subscriber::with_default(subscriber, || days::solve(day, part, data));
+        assert!(result.is_ok());
+
+        let log = buffer.0.lock().unwrap().clone();
+        String::from_utf8(log).unwrap()
+    }
+
+    #[test]
+    fn test_silent_by_default() {
+        assert_eq!("", solve_logged(0, 11, 2, "125 17"));
+    }
+
+    #[test]
+    fn test_blink_events() {
+        let log = solve_logged(1, 11, 2, "125 17");
+        assert_eq!(75, log.lines().count());
+        assert!(log.lines().all(|line| line.contains("blinked")));
+        assert!(log.contains("blink=75 stones=65601038650482"));
+    }
+}
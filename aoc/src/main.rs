@@ -0,0 +1,520 @@
+mod bench;
+mod client;
+mod days;
+mod fetch;
+mod log;
+mod submit;
+mod verify;
+
+use anyhow::{bail, Context, Result};
+use aoc_common::{
+    answer::Answer,
+    bench::Options,
+    error::with_file,
+    read_input, read_input_from,
+    record::{self, Recorder},
+    render::{self, encode},
+};
+use clap::{ArgAction, Parser, Subcommand, ValueEnum};
+use client::{Client, Config, Ureq};
+use std::{
+    fs::write,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    process,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use submit::{History, Verdict};
+
+/// Runs the Advent of Code 2024 solutions.
+#[derive(Parser)]
+#[command(name = "aoc")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+
+    /// Logs what the solutions do to stderr, `-vv` logs every single step.
+    #[arg(short, long, global = true, action = ArgAction::Count)]
+    verbose: u8,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solves one or both parts of a day.
+    Run {
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=days::DAYS as i64))]
+        day: u8,
+
+        /// Only solve this part, both parts are solved if omitted.
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Puzzle input file, `-` reads the input from stdin.
+        /// Defaults to the `input.txt` of the day.
+        #[arg(short, long)]
+        input: Option<String>,
+
+        #[arg(short, long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+    /// Solves both parts of every day that has a puzzle input.
+    Collect {
+        #[arg(short, long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+    /// Checks the answers of all days against the `answers.toml` of each day.
+    Verify {
+        /// Only check this day.
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=days::DAYS as i64))]
+        day: Option<u8>,
+    },
+    /// Times parsing and both parts of the days on their puzzle inputs.
+    Bench {
+        /// Only benchmark these days, all days are benchmarked if omitted.
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=days::DAYS as i64))]
+        day: Vec<u8>,
+
+        /// Unmeasured runs before the measurement.
+        #[arg(short, long, default_value_t = 1)]
+        warmup: usize,
+
+        /// Measured runs, the summary shows their median.
+        #[arg(short, long, default_value_t = 5)]
+        repeats: usize,
+
+        /// Stores the results as JSON.
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Results of an earlier run to compare against.
+        #[arg(short, long)]
+        baseline: Option<PathBuf>,
+
+        /// How many percent slower than the baseline a phase may get.
+        #[arg(short, long, default_value_t = 20.0)]
+        threshold: f64,
+    },
+    /// Generates a random puzzle input of a day, the same seed always creates
+    /// the same input. Planted answers are printed to stderr.
+    Generate {
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=days::DAYS as i64))]
+        day: u8,
+
+        #[arg(short, long, default_value_t = 0)]
+        seed: u64,
+
+        /// Scales the input, defaults to the size of the actual puzzle input.
+        #[arg(long)]
+        size: Option<usize>,
+
+        /// Writes the input to this file instead of stdout.
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Solves the input and fails if a planted answer is wrong.
+        #[arg(short, long)]
+        check: bool,
+    },
+    /// Draws the state of a day, like the path of the guard on day 6.
+    Render {
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=days::DAYS as i64))]
+        day: u8,
+
+        /// Puzzle input file, `-` reads the input from stdin.
+        /// Defaults to the `input.txt` of the day.
+        #[arg(short, long)]
+        input: Option<String>,
+
+        /// Defaults to the extension of the output file, colored text if the
+        /// snapshot is written to stdout.
+        #[arg(short, long, value_enum)]
+        format: Option<ImageFormat>,
+
+        /// Writes the snapshot to this file instead of stdout.
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// The side of a cell in pixels.
+        #[arg(short, long, default_value_t = 4)]
+        scale: usize,
+    },
+    /// Downloads the puzzle inputs into the directories of the days, an input
+    /// that is there already is never downloaded again.
+    Fetch {
+        /// Only download these days, all days are downloaded if omitted.
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=days::DAYS as i64))]
+        day: Vec<u8>,
+    },
+    /// Submits the answer to a part of a day. Every attempt is kept in the
+    /// `submissions.jsonl` of the workspace, a rejected answer is never
+    /// submitted again.
+    Submit {
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=days::DAYS as i64))]
+        day: u8,
+
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+
+        /// Defaults to solving the `input.txt` of the day.
+        answer: Option<String>,
+    },
+    /// Records the steps of a simulation, like the robots of day 14, as an
+    /// animated GIF or a sequence of images.
+    Record {
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=days::DAYS as i64))]
+        day: u8,
+
+        /// Puzzle input file, `-` reads the input from stdin.
+        /// Defaults to the `input.txt` of the day.
+        #[arg(short, long)]
+        input: Option<String>,
+
+        /// A `.gif` file for an animation, otherwise a directory the frames
+        /// are written to one file each.
+        #[arg(short, long)]
+        output: PathBuf,
+
+        /// The format of the single frames.
+        #[arg(short, long, value_enum, default_value_t = ImageFormat::Png)]
+        format: ImageFormat,
+
+        /// Only keeps every nth step.
+        #[arg(short, long, default_value_t = 1)]
+        every: usize,
+
+        /// Steps left out at the start.
+        #[arg(long, default_value_t = 0)]
+        skip: usize,
+
+        /// The most frames kept, the final state is always added.
+        #[arg(short, long, default_value_t = 1000)]
+        limit: usize,
+
+        /// How long a frame of the animation is shown, in hundredths of a second.
+        #[arg(long, default_value_t = 5)]
+        delay: u16,
+
+        /// The side of a cell in pixels.
+        #[arg(short, long, default_value_t = 4)]
+        scale: usize,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    Text,
+    Json,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum ImageFormat {
+    Ascii,
+    Ansi,
+    Ppm,
+    Png,
+}
+
+impl From<ImageFormat> for render::Format {
+    fn from(format: ImageFormat) -> render::Format {
+        match format {
+            ImageFormat::Ascii => render::Format::Ascii,
+            ImageFormat::Ansi => render::Format::Ansi,
+            ImageFormat::Ppm => render::Format::Ppm,
+            ImageFormat::Png => render::Format::Png,
+        }
+    }
+}
+
+fn main() {
+    let cli = Cli::parse();
+    log::init(cli.verbose);
+
+    let result = match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            format,
+        } => run(day, part, input.as_deref(), format),
+        Command::Collect { format } => collect(format),
+        Command::Verify { day } => match day {
+            Some(day) => verify::verify([day]),
+            None => verify::verify(1..=days::DAYS),
+        },
+        Command::Bench {
+            day,
+            warmup,
+            repeats,
+            output,
+            baseline,
+            threshold,
+        } => {
+            let days = match day.is_empty() {
+                true => (1..=days::DAYS).collect(),
+                false => day,
+            };
+            bench::bench(
+                days,
+                Options { warmup, repeats },
+                output.as_deref(),
+                baseline.as_deref(),
+                threshold,
+            )
+        }
+        Command::Generate {
+            day,
+            seed,
+            size,
+            output,
+            check,
+        } => generate(day, seed, size, output.as_deref(), check),
+        Command::Render {
+            day,
+            input,
+            format,
+            output,
+            scale,
+        } => render(day, input.as_deref(), format, output.as_deref(), scale),
+        Command::Fetch { day } => {
+            let days = match day.is_empty() {
+                true => (1..=days::DAYS).collect(),
+                false => day,
+            };
+            fetch::fetch(client, days)
+        }
+        Command::Submit { day, part, answer } => submit(day, part, answer),
+        Command::Record {
+            day,
+            input,
+            output,
+            format,
+            every,
+            skip,
+            limit,
+            delay,
+            scale,
+        } => {
+            let recorder = Recorder::new(every).skip(skip).limit(limit);
+            record(
+                day,
+                input.as_deref(),
+                recorder,
+                &output,
+                format,
+                delay,
+                scale,
+            )
+        }
+    };
+
+    if let Err(err) = result {
+        eprintln!("error: {err:#}");
+        process::exit(1);
+    }
+}
+
+fn run(day: u8, part: Option<u8>, input: Option<&str>, format: Format) -> Result<()> {
+    let (name, data) = puzzle_input(day, input)?;
+    let parts = match part {
+        Some(part) => vec![part],
+        None => days::parts(day),
+    };
+
+    for part in parts {
+        let answer = days::answer(day, part, &data).map_err(|err| with_file(err, &name))?;
+        print_answer(&answer, format)?;
+    }
+
+    Ok(())
+}
+
+/// Runs all days with a puzzle input, a failing day is reported and the
+/// remaining days are still solved.
+fn collect(format: Format) -> Result<()> {
+    let mut failed = 0;
+
+    for day in 1..=days::DAYS {
+        let path = day_directory(day).join("input.txt");
+        if !path.exists() {
+            eprintln!("Day {day}: no puzzle input");
+            continue;
+        }
+
+        for part in days::parts(day) {
+            let answer = read_input(&path.to_string_lossy())
+                .and_then(|data| days::answer(day, part, &data))
+                .map_err(|err| with_file(err, &path.to_string_lossy()));
+            match answer {
+                Ok(answer) => print_answer(&answer, format)?,
+                Err(err) => {
+                    eprintln!("Day {day}, part {part}: {err:#}");
+                    failed += 1;
+                }
+            }
+        }
+    }
+
+    if failed > 0 {
+        bail!("{failed} parts failed");
+    }
+
+    Ok(())
+}
+
+fn generate(
+    day: u8,
+    seed: u64,
+    size: Option<usize>,
+    output: Option<&Path>,
+    check: bool,
+) -> Result<()> {
+    let generated = days::generate(day, seed, size, check)?;
+    match output {
+        Some(output) => write(output, &generated.input)
+            .with_context(|| format!("Failed to write input to {}", output.display()))?,
+        None => println!("{}", generated.input.trim_end()),
+    }
+
+    for part in [1, 2] {
+        if let Some(answer) = generated.answer(part) {
+            eprintln!("Day {day}, part {part}: {answer}");
+        }
+    }
+
+    Ok(())
+}
+
+fn render(
+    day: u8,
+    input: Option<&str>,
+    format: Option<ImageFormat>,
+    output: Option<&Path>,
+    scale: usize,
+) -> Result<()> {
+    let format = match (format, output) {
+        (Some(format), _) => format.into(),
+        (None, Some(output)) => render::Format::from_path(output).with_context(|| {
+            format!("Unknown image format of {}, use --format", output.display())
+        })?,
+        (None, None) => render::Format::Ansi,
+    };
+
+    let (name, data) = puzzle_input(day, input)?;
+    let canvas = days::render(day, &data).map_err(|err| with_file(err, &name))?;
+    let image = encode(&canvas, format, scale)?;
+
+    match output {
+        Some(output) => write(output, image)
+            .with_context(|| format!("Failed to write snapshot to {}", output.display()))?,
+        None => io::stdout().write_all(&image)?,
+    }
+
+    Ok(())
+}
+
+fn record(
+    day: u8,
+    input: Option<&str>,
+    mut recorder: Recorder,
+    output: &Path,
+    format: ImageFormat,
+    delay: u16,
+    scale: usize,
+) -> Result<()> {
+    let (name, data) = puzzle_input(day, input)?;
+    days::record(day, &data, &mut recorder).map_err(|err| with_file(err, &name))?;
+
+    if output
+        .extension()
+        .is_some_and(|extension| extension == "gif")
+    {
+        let animation = record::gif(recorder.frames(), scale, delay)?;
+        write(output, animation)
+            .with_context(|| format!("Failed to write animation to {}", output.display()))?;
+    } else {
+        record::write_sequence(recorder.frames(), output, format.into(), scale)?;
+    }
+
+    eprintln!(
+        "Day {day}: {} of {} steps recorded to {}",
+        recorder.frames().len(),
+        recorder.steps(),
+        output.display()
+    );
+    Ok(())
+}
+
+fn submit(day: u8, part: u8, answer: Option<String>) -> Result<()> {
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let (name, data) = puzzle_input(day, None)?;
+            days::answer(day, part, &data)
+                .map_err(|err| with_file(err, &name))?
+                .answer
+        }
+    };
+
+    let client = client()?;
+    let mut history = History::load(workspace_directory().join(History::FILENAME))?;
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+    let attempt = submit::submit(&client, &mut history, day, part, &answer, now)?;
+
+    let result = format!(
+        "Day {day}, part {part}: {} is {}",
+        attempt.answer, attempt.verdict
+    );
+    match attempt.verdict {
+        Verdict::Correct => {
+            println!("{result}");
+            Ok(())
+        }
+        _ if attempt.wait > 0 => bail!("{result}, wait {}s before answering again", attempt.wait),
+        _ => bail!("{result}"),
+    }
+}
+
+fn print_answer(answer: &Answer, format: Format) -> Result<()> {
+    match format {
+        Format::Text => println!("{answer}"),
+        Format::Json => println!("{}", answer.to_json()?),
+    }
+
+    Ok(())
+}
+
+/// A client for the puzzle website with the settings of the `aoc.toml`.
+fn client() -> Result<Client> {
+    let config = Config::load(workspace_directory().join(Config::FILENAME))?;
+    Ok(Client::new(
+        Ureq::default(),
+        &config.url(),
+        &config.session()?,
+    ))
+}
+
+fn workspace_directory() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("The runner is part of the workspace")
+}
+
+fn day_directory(day: u8) -> PathBuf {
+    workspace_directory().join(format!("advent_of_code_{day:02}"))
+}
+
+/// Reads the puzzle input of a run, `-` reads it from stdin.
+/// Returns the name of the input for diagnostics together with the input.
+fn puzzle_input(day: u8, input: Option<&str>) -> Result<(String, String)> {
+    match input {
+        Some("-") => Ok(("<stdin>".to_string(), read_input_from(io::stdin().lock())?)),
+        Some(filename) => Ok((filename.to_string(), read_input(filename)?)),
+        None => {
+            let path = day_directory(day).join("input.txt");
+            match path.to_str() {
+                Some(path) => Ok((path.to_string(), read_input(path)?)),
+                None => bail!("Invalid input path {}", path.display()),
+            }
+        }
+    }
+}
@@ -0,0 +1,363 @@
+use crate::client::{Client, Http};
+use anyhow::{bail, ensure, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt::{self, Display, Formatter},
+    fs::{read_to_string, OpenOptions},
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+/// What the website said to an answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong without a hint, like most text answers.
+    Wrong,
+    /// The answer came too soon after the last one and wasn't checked.
+    TooSoon,
+    /// The part is solved already or not unlocked yet, the answer wasn't checked.
+    WrongLevel,
+}
+
+impl Verdict {
+    /// Whether the answer is known to be wrong.
+    pub fn is_rejected(self) -> bool {
+        matches!(self, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let verdict = match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too high",
+            Verdict::TooLow => "too low",
+            Verdict::Wrong => "wrong",
+            Verdict::TooSoon => "too soon",
+            Verdict::WrongLevel => "not for this part",
+        };
+        write!(f, "{verdict}")
+    }
+}
+
+/// The verdict on the answer page and the seconds to wait before the next
+/// answer to the same part.
+pub fn parse_reply(page: &str) -> Result<(Verdict, u64)> {
+    if page.contains("That's the right answer") {
+        return Ok((Verdict::Correct, 0));
+    }
+
+    if page.contains("You gave an answer too recently") {
+        return Ok((Verdict::TooSoon, left_to_wait(page).unwrap_or(60)));
+    }
+
+    if page.contains("That's not the right answer") {
+        let verdict = if page.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if page.contains("your answer is too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        };
+        return Ok((verdict, penalty(page).unwrap_or(60)));
+    }
+
+    if page.contains("You don't seem to be solving the right level") {
+        return Ok((Verdict::WrongLevel, 0));
+    }
+
+    bail!("Unexpected answer page")
+}
+
+// the time left after answering too soon, like "You have 1m 5s left to wait"
+fn left_to_wait(page: &str) -> Option<u64> {
+    let start = page.find("You have ")? + "You have ".len();
+    let end = start + page[start..].find(" left to wait")?;
+
+    page[start..end]
+        .split_whitespace()
+        .map(|part| {
+            let unit = part.chars().last()?;
+            let number: u64 = part[..part.len() - unit.len_utf8()].parse().ok()?;
+            match unit {
+                'h' => Some(number * 3600),
+                'm' => Some(number * 60),
+                's' => Some(number),
+                _ => None,
+            }
+        })
+        .sum()
+}
+
+// the time a wrong answer locks the part, like "please wait 5 minutes"
+fn penalty(page: &str) -> Option<u64> {
+    let page = page.to_lowercase();
+    let start = page.find("please wait ")? + "please wait ".len();
+
+    let mut words = page[start..].split_whitespace();
+    let count: u64 = match words.next()? {
+        "one" => 1,
+        count => count.parse().ok()?,
+    };
+    match words.next()? {
+        unit if unit.starts_with("minute") => Some(count * 60),
+        unit if unit.starts_with("second") => Some(count),
+        _ => None,
+    }
+}
+
+/// A submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attempt {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// When the answer was submitted, in seconds since the Unix epoch.
+    pub time: u64,
+    /// Seconds to wait before the next answer to the part.
+    pub wait: u64,
+}
+
+/// Every answer submitted so far, one JSON object per line.
+#[derive(Debug)]
+pub struct History {
+    path: PathBuf,
+    attempts: Vec<Attempt>,
+}
+
+impl History {
+    pub const FILENAME: &str = "submissions.jsonl";
+
+    /// Loads the history, a missing file is an empty history.
+    pub fn load(path: impl AsRef<Path>) -> Result<History> {
+        let path = path.as_ref();
+        let mut attempts = Vec::new();
+        if path.exists() {
+            let data = read_to_string(path)
+                .with_context(|| format!("Failed to read history {}", path.display()))?;
+            for (index, line) in data.lines().enumerate() {
+                if !line.trim().is_empty() {
+                    let attempt = serde_json::from_str(line).with_context(|| {
+                        format!(
+                            "Invalid attempt in line {} of {}",
+                            index + 1,
+                            path.display()
+                        )
+                    })?;
+                    attempts.push(attempt);
+                }
+            }
+        }
+
+        Ok(History {
+            path: path.to_path_buf(),
+            attempts,
+        })
+    }
+
+    /// The attempts at `part` of `day`, the oldest first.
+    pub fn attempts(&self, day: u8, part: u8) -> impl Iterator<Item = &Attempt> {
+        self.attempts
+            .iter()
+            .filter(move |attempt| attempt.day == day && attempt.part == part)
+    }
+
+    /// Fails if `answer` mustn't be submitted at `now`: the part is solved
+    /// already, the answer was rejected before or is beyond a rejected bound,
+    /// or the website asked to wait.
+    ///
+    /// Only integers have bounds, the website only says too high or too low
+    /// for them. Text answers, like the output of day 17 or the password of
+    /// day 23, are only refused if the very same text was rejected.
+    pub fn check(&self, day: u8, part: u8, answer: &str, now: u64) -> Result<()> {
+        let number: Option<i128> = answer.parse().ok();
+
+        for attempt in self.attempts(day, part) {
+            let reason = match attempt.verdict {
+                Verdict::Correct => Some(format!("it was solved with {}", attempt.answer)),
+                verdict if verdict.is_rejected() && attempt.answer == answer => {
+                    Some(format!("the answer was {verdict} before"))
+                }
+                Verdict::TooHigh => number
+                    .zip(attempt.answer.parse::<i128>().ok())
+                    .filter(|(number, high)| number >= high)
+                    .map(|(_, high)| format!("{high} was too high already")),
+                Verdict::TooLow => number
+                    .zip(attempt.answer.parse::<i128>().ok())
+                    .filter(|(number, low)| number <= low)
+                    .map(|(_, low)| format!("{low} was too low already")),
+                _ => None,
+            };
+            if let Some(reason) = reason {
+                bail!("Not submitting {answer} to day {day}, part {part}, {reason}");
+            }
+        }
+
+        if let Some(last) = self.attempts(day, part).last() {
+            let ready = last.time + last.wait;
+            ensure!(
+                now >= ready,
+                "Wait {}s before answering day {day}, part {part} again",
+                ready - now
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Adds `attempt` to the history and its file.
+    pub fn record(&mut self, attempt: Attempt) -> Result<()> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .with_context(|| format!("Failed to open history {}", self.path.display()))?;
+        writeln!(file, "{}", serde_json::to_string(&attempt)?)
+            .with_context(|| format!("Failed to write history {}", self.path.display()))?;
+
+        self.attempts.push(attempt);
+        Ok(())
+    }
+}
+
+/// Submits `answer` to `part` of `day` at `now`, unless the history knows that
+/// it's pointless, and records the attempt.
+pub fn submit(
+    client: &Client<impl Http>,
+    history: &mut History,
+    day: u8,
+    part: u8,
+    answer: &str,
+    now: u64,
+) -> Result<Attempt> {
+    let answer = answer.trim();
+    ensure!(!answer.is_empty(), "The answer is empty");
+    history.check(day, part, answer, now)?;
+
+    let page = client.answer(day, part, answer)?;
+    let (verdict, wait) = parse_reply(&page)?;
+
+    let attempt = Attempt {
+        day,
+        part,
+        answer: answer.to_string(),
+        verdict,
+        time: now,
+        wait,
+    };
+    history.record(attempt.clone())?;
+
+    Ok(attempt)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::{mock::MockServer, Ureq};
+    use std::fs;
+
+    const CORRECT: &str = "<article><p>That's the right answer!  You are <span class=\"quiet\">one gold star</span> closer to finding the Chief Historian.</p></article>";
+    const TOO_HIGH: &str = "<article><p>That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data. Please wait one minute before trying again. [<a href=\"/2024/day/1\">Return to Day 1</a>]</p></article>";
+    const TOO_RECENT: &str = "<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait. [<a href=\"/2024/day/1\">Return to Day 1</a>]</p></article>";
+
+    fn history(name: &str) -> History {
+        let path = std::env::temp_dir().join(format!("aoc_{name}_{}.jsonl", std::process::id()));
+        let _ = fs::remove_file(&path);
+        History::load(path).unwrap()
+    }
+
+    #[test]
+    fn test_parse_reply() {
+        assert_eq!(Some((Verdict::Correct, 0)), parse_reply(CORRECT).ok());
+        assert_eq!(Some((Verdict::TooHigh, 60)), parse_reply(TOO_HIGH).ok());
+        assert_eq!(Some((Verdict::TooSoon, 65)), parse_reply(TOO_RECENT).ok());
+
+        let too_low = "That's not the right answer; your answer is too low.  Please wait 5 minutes before trying again.";
+        assert_eq!(Some((Verdict::TooLow, 300)), parse_reply(too_low).ok());
+        let wrong = "That's not the right answer.  If you're stuck, please wait 2 minutes before trying again.";
+        assert_eq!(Some((Verdict::Wrong, 120)), parse_reply(wrong).ok());
+        let level = "You don't seem to be solving the right level.  Did you already complete it?";
+        assert_eq!(Some((Verdict::WrongLevel, 0)), parse_reply(level).ok());
+        assert!(parse_reply("<html>Log in</html>").is_err());
+    }
+
+    #[test]
+    fn test_history() {
+        let mut history = history("history");
+        let attempt = |answer: &str, verdict, time| Attempt {
+            day: 1,
+            part: 1,
+            answer: answer.to_string(),
+            verdict,
+            time,
+            wait: 60,
+        };
+        history.record(attempt("100", Verdict::TooHigh, 0)).unwrap();
+        history.record(attempt("10", Verdict::TooLow, 100)).unwrap();
+
+        assert!(history.check(1, 1, "50", 100).is_err());
+        assert!(history.check(1, 1, "50", 160).is_ok());
+        assert!(history.check(1, 1, "100", 160).is_err());
+        assert!(history.check(1, 1, "120", 160).is_err());
+        assert!(history.check(1, 1, "10", 160).is_err());
+        assert!(history.check(1, 1, "-5", 160).is_err());
+        assert!(history.check(1, 2, "100", 0).is_ok());
+
+        // beyond 64 bits the bounds still hold, text answers have none
+        assert!(history.check(1, 1, "10000000000000000000000", 160).is_err());
+        assert!(history.check(1, 1, "co,de,ka", 160).is_ok());
+
+        history
+            .record(attempt("50", Verdict::Correct, 200))
+            .unwrap();
+        assert!(history.check(1, 1, "50", 1000).is_err());
+
+        // the file holds the same history
+        let loaded = History::load(&history.path).unwrap();
+        assert_eq!(history.attempts, loaded.attempts);
+        assert_eq!(3, loaded.attempts(1, 1).count());
+        fs::remove_file(&history.path).unwrap();
+    }
+
+    #[test]
+    fn test_submit() {
+        let server = MockServer::start(vec![(200, TOO_HIGH), (200, TOO_RECENT), (200, CORRECT)]);
+        let client = Client::new(Ureq::default(), &server.url, "secret");
+        let mut history = history("submit");
+
+        let result = submit(&client, &mut history, 1, 2, "31\n", 1000);
+        assert_eq!(
+            Some(Verdict::TooHigh),
+            result.ok().map(|attempt| attempt.verdict)
+        );
+
+        // refused without asking the website
+        assert!(submit(&client, &mut history, 1, 2, "31", 2000).is_err());
+        assert!(submit(&client, &mut history, 1, 2, "12", 1030).is_err());
+
+        let result = submit(&client, &mut history, 1, 2, "12", 1060);
+        assert_eq!(
+            Some((Verdict::TooSoon, 65)),
+            result.ok().map(|a| (a.verdict, a.wait))
+        );
+        assert!(submit(&client, &mut history, 1, 2, "12", 1100).is_err());
+
+        let result = submit(&client, &mut history, 1, 2, "12", 1125);
+        assert_eq!(
+            Some(Verdict::Correct),
+            result.ok().map(|attempt| attempt.verdict)
+        );
+        assert!(submit(&client, &mut history, 1, 2, "13", 5000).is_err());
+
+        let requests = server.requests();
+        assert_eq!(3, requests.len());
+        assert_eq!("level=2&answer=31", requests[0].body);
+        assert_eq!(3, History::load(&history.path).unwrap().attempts.len());
+        fs::remove_file(&history.path).unwrap();
+    }
+}
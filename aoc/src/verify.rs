@@ -0,0 +1,82 @@
+use crate::{day_directory, days};
+use anyhow::{bail, Result};
+use aoc_common::{answers::Answers, read_input};
+use std::fmt::{self, Display, Formatter};
+
+/// The outcome of solving a part for one input of a day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Status {
+    Pass,
+    Fail(String),
+    /// There is no known answer yet, holds the answer that was found.
+    Unknown(String),
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Pass => write!(f, "pass"),
+            Status::Fail(reason) => write!(f, "fail ({reason})"),
+            Status::Unknown(answer) => write!(f, "unknown ({answer})"),
+        }
+    }
+}
+
+/// Checks the answers of `days` against their answers registry and reports
+/// every input, fails if any answer is wrong.
+pub fn verify(days: impl IntoIterator<Item = u8>) -> Result<()> {
+    let (mut passed, mut failed, mut unknown) = (0, 0, 0);
+
+    for day in days {
+        let answers = Answers::load(day_directory(day).join(Answers::FILENAME))?;
+        for part in days::parts(day) {
+            for input in inputs(day, part, &answers) {
+                let status = check(day, part, &input, answers.get(part, &input));
+                println!("Day {day:2}, part {part}, {input}: {status}");
+
+                match status {
+                    Status::Pass => passed += 1,
+                    Status::Fail(_) => failed += 1,
+                    Status::Unknown(_) => unknown += 1,
+                }
+            }
+        }
+    }
+
+    println!("{passed} passed, {failed} failed, {unknown} unknown");
+    if failed > 0 {
+        bail!("{failed} answers are wrong");
+    }
+
+    Ok(())
+}
+
+/// The inputs of a part with a known answer and the puzzle input, if it exists.
+fn inputs(day: u8, part: u8, answers: &Answers) -> Vec<String> {
+    let mut inputs: Vec<String> = answers.inputs(part).map(str::to_string).collect();
+    if !inputs.iter().any(|input| input == "input") && day_directory(day).join("input.txt").exists()
+    {
+        inputs.push("input".to_string());
+    }
+
+    // the examples first, they are usually faster and easier to debug
+    inputs.sort_by_key(|input| input == "input");
+    inputs
+}
+
+fn check(day: u8, part: u8, input: &str, expected: Option<String>) -> Status {
+    let path = day_directory(day).join(format!("{input}.txt"));
+    let answer = match path.to_str() {
+        Some(path) => read_input(path).and_then(|data| days::solve(day, part, &data)),
+        None => return Status::Fail(format!("invalid input path {}", path.display())),
+    };
+
+    match (answer, expected) {
+        (Ok(answer), Some(expected)) if answer == expected => Status::Pass,
+        (Ok(answer), Some(expected)) => {
+            Status::Fail(format!("expected {expected}, found {answer}"))
+        }
+        (Ok(answer), None) => Status::Unknown(answer),
+        (Err(err), _) => Status::Fail(format!("{err:#}")),
+    }
+}
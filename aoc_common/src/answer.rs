@@ -0,0 +1,137 @@
+use crate::{
+    bench::format_nanos, error::with_file, input::normalize, read_input, solution, Solution,
+};
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::{
+    env,
+    fmt::{self, Display, Formatter},
+    time::Instant,
+};
+
+/// The answer of a part together with how it was found.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Answer {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    /// Nanoseconds spent parsing the input and solving the part.
+    pub elapsed: u64,
+    /// Identifies the input the answer belongs to, see [`input_hash`].
+    pub input_hash: String,
+}
+
+impl Answer {
+    /// Solves one part of the day `S` for the puzzle input `data`.
+    pub fn solve<S: Solution>(day: u8, part: u8, data: &str) -> Result<Answer> {
+        let start = Instant::now();
+        let answer = solution::solve::<S>(part, data)?;
+        let elapsed = start.elapsed().as_nanos() as u64;
+
+        Ok(Answer {
+            day,
+            part,
+            answer,
+            elapsed,
+            input_hash: input_hash(data),
+        })
+    }
+
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Day {}, part {}: {} ({})",
+            self.day,
+            self.part,
+            self.answer,
+            format_nanos(self.elapsed)
+        )
+    }
+}
+
+/// A 64 bit FNV-1a hash of the normalised input as hex, it doesn't change
+/// between runs or Rust versions and ignores CRLF line endings and the like.
+pub fn input_hash(data: &str) -> String {
+    let hash = normalize(data)
+        .bytes()
+        .fold(0xcbf29ce484222325_u64, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+        });
+    format!("{hash:016x}")
+}
+
+/// The `main` of a day binary, solves `parts` for the `input.txt` of the day
+/// and prints the answers, as JSON lines if `--json` is passed.
+pub fn main<S: Solution>(day: u8, parts: &[u8]) -> Result<()> {
+    let json = env::args().skip(1).any(|arg| arg == "--json");
+    let filename = "input.txt";
+    let data = read_input(filename)?;
+
+    for &part in parts {
+        let answer =
+            Answer::solve::<S>(day, part, &data).map_err(|err| with_file(err, filename))?;
+        match json {
+            true => println!("{}", answer.to_json()?),
+            false => println!("{answer}"),
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Count;
+
+    impl Solution for Count {
+        type Input = Vec<String>;
+
+        fn parse(data: &str) -> Result<Vec<String>> {
+            Ok(data.lines().map(str::to_string).collect())
+        }
+
+        fn part1(input: &Vec<String>) -> Result<impl Display> {
+            Ok(input.len())
+        }
+
+        fn part2(input: &Vec<String>) -> Result<impl Display> {
+            Ok(input.concat())
+        }
+    }
+
+    #[test]
+    fn test_input_hash() {
+        assert_eq!("cbf29ce484222325", input_hash(""));
+        assert_eq!(input_hash("a\n"), input_hash("a"));
+        assert_eq!(input_hash("a\nb\n"), input_hash("\u{feff}a\r\nb"));
+        assert_ne!(input_hash("a\nb\n"), input_hash("b\na\n"));
+    }
+
+    #[test]
+    fn test_answer() {
+        let result = Answer::solve::<Count>(3, 2, "ab\ncd\n");
+        assert!(result.is_ok());
+
+        let answer = result.unwrap();
+        assert_eq!(
+            (3, 2, "abcd"),
+            (answer.day, answer.part, answer.answer.as_str())
+        );
+        assert_eq!(input_hash("ab\ncd\n"), answer.input_hash);
+        assert!(answer.to_string().starts_with("Day 3, part 2: abcd ("));
+
+        let json = answer.to_json().unwrap();
+        assert!(json.starts_with(r#"{"day":3,"part":2,"answer":"abcd","elapsed":"#));
+        assert_eq!(answer, serde_json::from_str(&json).unwrap());
+
+        assert!(Answer::solve::<Count>(3, 3, "ab\n").is_err());
+    }
+}
@@ -0,0 +1,146 @@
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display, Formatter},
+    fs::read_to_string,
+    path::Path,
+};
+
+/// The registry of known answers, the `answers.toml` in the directory of a day.
+///
+/// Every part maps the name of an input file without the `.txt` extension to
+/// its expected answer. An answer that is not known yet is left out.
+///
+/// ```toml
+/// [part1]
+/// input_small = 11
+/// input = 1579939
+///
+/// [part2]
+/// input_small = 31
+/// ```
+#[derive(Debug, Default, Deserialize)]
+pub struct Answers {
+    #[serde(default)]
+    part1: BTreeMap<String, Answer>,
+    #[serde(default)]
+    part2: BTreeMap<String, Answer>,
+}
+
+/// Answers are either numbers or text like `co,de,ka,ta`.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{number}"),
+            Answer::Text(text) => write!(f, "{text}"),
+        }
+    }
+}
+
+impl Answers {
+    pub const FILENAME: &str = "answers.toml";
+
+    /// Loads the registry, a missing file is an empty registry.
+    pub fn load(path: impl AsRef<Path>) -> Result<Answers> {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Ok(Answers::default());
+        }
+
+        let data = read_to_string(path)
+            .with_context(|| format!("Failed to read answers file {}", path.display()))?;
+        Answers::parse(&data).with_context(|| format!("Invalid answers file {}", path.display()))
+    }
+
+    pub fn parse(data: &str) -> Result<Answers> {
+        Ok(toml::from_str(data)?)
+    }
+
+    /// The expected answer of `part` for the input file `input`, the `.txt`
+    /// extension is optional.
+    pub fn get(&self, part: u8, input: &str) -> Option<String> {
+        let input = input.strip_suffix(".txt").unwrap_or(input);
+        self.part(part)?.get(input).map(Answer::to_string)
+    }
+
+    /// The names of all inputs of `part` that have a known answer.
+    pub fn inputs(&self, part: u8) -> impl Iterator<Item = &str> {
+        self.part(part)
+            .into_iter()
+            .flat_map(|answers| answers.keys().map(String::as_str))
+    }
+
+    fn part(&self, part: u8) -> Option<&BTreeMap<String, Answer>> {
+        match part {
+            1 => Some(&self.part1),
+            2 => Some(&self.part2),
+            _ => None,
+        }
+    }
+}
+
+/// The expected answer from the `answers.toml` of the crate under test.
+///
+/// Panics if there is no known answer, tests of unknown answers are ignored.
+pub fn expected(part: u8, input: &str) -> String {
+    Answers::load(Answers::FILENAME)
+        .unwrap()
+        .get(part, input)
+        .unwrap_or_else(|| {
+            panic!(
+                "No answer for part {part} of {input} in {}",
+                Answers::FILENAME
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANSWERS: &str = r#"
+[part1]
+input_small = 11
+input = 1579939
+
+[part2]
+input_small = "co,de,ka,ta"
+"#;
+
+    #[test]
+    fn test_get() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+        assert_eq!(Some("11".to_string()), answers.get(1, "input_small.txt"));
+        assert_eq!(Some("1579939".to_string()), answers.get(1, "input"));
+        assert_eq!(
+            Some("co,de,ka,ta".to_string()),
+            answers.get(2, "input_small")
+        );
+        assert_eq!(None, answers.get(2, "input.txt"));
+        assert_eq!(None, answers.get(3, "input.txt"));
+    }
+
+    #[test]
+    fn test_inputs() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+        assert_eq!(
+            vec!["input", "input_small"],
+            answers.inputs(1).collect::<Vec<_>>()
+        );
+        assert_eq!(vec!["input_small"], answers.inputs(2).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_missing_file() {
+        let answers = Answers::load("does_not_exist.toml").unwrap();
+        assert_eq!(0, answers.inputs(1).count());
+    }
+}
@@ -0,0 +1,140 @@
+use crate::Solution;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+/// How often a phase is run, the warm-up runs are not measured.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Options {
+    pub warmup: usize,
+    pub repeats: usize,
+}
+
+impl Default for Options {
+    fn default() -> Options {
+        Options {
+            warmup: 1,
+            repeats: 5,
+        }
+    }
+}
+
+/// The measured times of a phase in nanoseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stats {
+    pub min: u64,
+    pub median: u64,
+    pub mean: u64,
+}
+
+impl Stats {
+    fn new(mut samples: Vec<Duration>) -> Stats {
+        samples.sort_unstable();
+        let nanos = |duration: Duration| duration.as_nanos() as u64;
+        let total: Duration = samples.iter().sum();
+
+        Stats {
+            min: samples.first().copied().map_or(0, nanos),
+            median: samples.get(samples.len() / 2).copied().map_or(0, nanos),
+            mean: nanos(total) / samples.len().max(1) as u64,
+        }
+    }
+}
+
+/// The times of all phases of a day, `part2` is `None` if there is no second part.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Timings {
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Option<Stats>,
+}
+
+/// Formats nanoseconds with the largest unit that keeps the value above one.
+pub fn format_nanos(nanos: u64) -> String {
+    match nanos {
+        0..1_000 => format!("{nanos} ns"),
+        1_000..1_000_000 => format!("{:.1} µs", nanos as f64 / 1e3),
+        1_000_000..1_000_000_000 => format!("{:.1} ms", nanos as f64 / 1e6),
+        _ => format!("{:.2} s", nanos as f64 / 1e9),
+    }
+}
+
+/// Times `f`, an error of any run aborts the measurement.
+pub fn time<T>(options: Options, mut f: impl FnMut() -> Result<T>) -> Result<Stats> {
+    for _ in 0..options.warmup {
+        black_box(f()?);
+    }
+
+    let mut samples = Vec::with_capacity(options.repeats);
+    for _ in 0..options.repeats.max(1) {
+        let start = Instant::now();
+        black_box(f()?);
+        samples.push(start.elapsed());
+    }
+
+    Ok(Stats::new(samples))
+}
+
+/// Times parsing and both parts of the puzzle, the parts share a single parsed input.
+pub fn bench<S: Solution>(data: &str, options: Options, part2: bool) -> Result<Timings> {
+    let parse = time(options, || S::parse(data))?;
+    let input = S::parse(data)?;
+    let part1 = time(options, || {
+        S::part1(&input).map(|answer| answer.to_string())
+    })?;
+    let part2 = match part2 {
+        true => Some(time(options, || {
+            S::part2(&input).map(|answer| answer.to_string())
+        })?),
+        false => None,
+    };
+
+    Ok(Timings {
+        parse,
+        part1,
+        part2,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let samples = [5, 1, 3].map(Duration::from_nanos).to_vec();
+        let stats = Stats::new(samples);
+        assert_eq!(1, stats.min);
+        assert_eq!(3, stats.median);
+        assert_eq!(3, stats.mean);
+    }
+
+    #[test]
+    fn test_format_nanos() {
+        assert_eq!("999 ns", format_nanos(999));
+        assert_eq!("1.5 µs", format_nanos(1_500));
+        assert_eq!("12.0 ms", format_nanos(12_000_000));
+        assert_eq!("3.25 s", format_nanos(3_250_000_000));
+    }
+
+    #[test]
+    fn test_time() {
+        let mut runs = 0;
+        let options = Options {
+            warmup: 2,
+            repeats: 3,
+        };
+        let result = time(options, || {
+            runs += 1;
+            Ok(runs)
+        });
+        assert!(result.is_ok());
+        assert_eq!(5, runs);
+
+        let result = time(options, || anyhow::bail!("failed") as Result<()>);
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,72 @@
+/// One of the four directions on a map where `y` grows downwards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn turn_right(&self) -> Direction {
+        Direction::ALL[(*self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(&self) -> Direction {
+        Direction::ALL[(*self as usize + 3) % 4]
+    }
+
+    pub fn reverse(&self) -> Direction {
+        Direction::ALL[(*self as usize + 2) % 4]
+    }
+
+    /// Parses one of the arrows `^`, `>`, `v` or `<`.
+    pub fn from_arrow(c: char) -> Option<Direction> {
+        match c {
+            '^' => Some(Direction::Up),
+            '>' => Some(Direction::Right),
+            'v' => Some(Direction::Down),
+            '<' => Some(Direction::Left),
+            _ => None,
+        }
+    }
+
+    /// The arrow pointing in the direction, the reverse of [`Direction::from_arrow`].
+    pub fn arrow(&self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::Right => '>',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turns() {
+        assert_eq!(Direction::Right, Direction::Up.turn_right());
+        assert_eq!(Direction::Left, Direction::Up.turn_left());
+        assert_eq!(Direction::Down, Direction::Up.reverse());
+        assert_eq!(Direction::Up, Direction::Left.turn_right());
+    }
+
+    #[test]
+    fn test_from_arrow() {
+        assert_eq!(Some(Direction::Down), Direction::from_arrow('v'));
+        assert_eq!(None, Direction::from_arrow('x'));
+        for direction in Direction::ALL {
+            assert_eq!(Some(direction), Direction::from_arrow(direction.arrow()));
+        }
+    }
+}
@@ -0,0 +1,248 @@
+use std::{
+    any::type_name,
+    convert::Infallible,
+    error::Error,
+    fmt::{self, Display, Formatter},
+    num::{IntErrorKind, ParseIntError},
+    str::FromStr,
+};
+
+/// An unexpected character in the puzzle input.
+///
+/// Cell conversions create the error with just the character, the line and
+/// column are added by the parser that knows where the character was found
+/// and the file by [`crate::Solution::parse_file`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    file: Option<String>,
+    position: Option<(usize, usize)>,
+    found: Option<char>,
+    expected: String,
+}
+
+impl ParseError {
+    /// An error for the character `found`, `None` if the line ended too early.
+    pub fn new(found: Option<char>, expected: impl Into<String>) -> ParseError {
+        ParseError {
+            file: None,
+            position: None,
+            found,
+            expected: expected.into(),
+        }
+    }
+
+    /// An error for the character at the byte `offset` of the line with the
+    /// zero based `index`.
+    pub fn in_line(
+        index: usize,
+        line: &str,
+        offset: usize,
+        expected: impl Into<String>,
+    ) -> ParseError {
+        let column = line[..offset].chars().count();
+        ParseError::new(line[offset..].chars().next(), expected).at(index + 1, column + 1)
+    }
+
+    /// Sets the position to the start of `field`, a slice of the line with
+    /// the zero based `index`.
+    pub fn at_field(self, index: usize, line: &str, field: &str) -> ParseError {
+        let column = line[..offset_in(line, field)].chars().count();
+        self.at(index + 1, column + 1)
+    }
+
+    /// Sets the one based line and column of the character.
+    pub fn at(mut self, line: usize, column: usize) -> ParseError {
+        self.position = Some((line, column));
+        self
+    }
+
+    pub fn in_file(mut self, file: &str) -> ParseError {
+        self.file = Some(file.to_string());
+        self
+    }
+
+    pub fn file(&self) -> Option<&str> {
+        self.file.as_deref()
+    }
+
+    pub fn line(&self) -> Option<usize> {
+        self.position.map(|(line, _)| line)
+    }
+
+    pub fn column(&self) -> Option<usize> {
+        self.position.map(|(_, column)| column)
+    }
+
+    pub fn found(&self) -> Option<char> {
+        self.found
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match (&self.file, self.position) {
+            (Some(file), Some((line, column))) => write!(f, "{file}:{line}:{column}: ")?,
+            (None, Some((line, column))) => write!(f, "line {line}, column {column}: ")?,
+            (Some(file), None) => write!(f, "{file}: ")?,
+            (None, None) => {}
+        }
+
+        match self.found {
+            Some(found) => write!(f, "expected {}, found {found:?}", self.expected),
+            None => write!(f, "expected {}, found the end of the line", self.expected),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// Allows infallible cell conversions, like `char` itself, wherever a
+/// conversion returning a [`ParseError`] is expected.
+impl From<Infallible> for ParseError {
+    fn from(never: Infallible) -> ParseError {
+        match never {}
+    }
+}
+
+/// Adds `file` to the [`ParseError`] in `err`, other errors are returned as they are.
+pub fn with_file(err: anyhow::Error, file: &str) -> anyhow::Error {
+    match err.downcast::<ParseError>() {
+        Ok(err) => err.in_file(file).into(),
+        Err(err) => err,
+    }
+}
+
+/// Parses the number `field`, a slice of the line with the zero based `index`.
+/// The error points to the first character that is not part of a number, or
+/// to the start of a number that doesn't fit into `T`.
+pub fn parse_number<T>(index: usize, line: &str, field: &str) -> Result<T, ParseError>
+where
+    T: FromStr<Err = ParseIntError>,
+{
+    field.parse().map_err(|err: ParseIntError| {
+        let start = offset_in(line, field);
+        match err.kind() {
+            IntErrorKind::PosOverflow | IntErrorKind::NegOverflow => {
+                let expected = format!("a number that fits into {}", type_name::<T>());
+                ParseError::in_line(index, line, start, expected)
+            }
+            _ => {
+                // only signed numbers may start with a minus
+                let signed = "-1".parse::<T>().is_ok();
+                let offset = field
+                    .char_indices()
+                    .find(|&(i, c)| {
+                        !(c.is_ascii_digit() || (i == 0 && (c == '+' || (signed && c == '-'))))
+                    })
+                    .map_or(field.len(), |(i, _)| i);
+                ParseError::in_line(index, line, start + offset, "a number")
+            }
+        }
+    })
+}
+
+/// The rest of `field` after the expected `prefix`, `field` is a slice of the
+/// line with the zero based `index`. The error points to the first character
+/// that differs from the prefix.
+pub fn expect_prefix<'a>(
+    index: usize,
+    line: &str,
+    field: &'a str,
+    prefix: &str,
+) -> Result<&'a str, ParseError> {
+    field.strip_prefix(prefix).ok_or_else(|| {
+        let matching: usize = field
+            .chars()
+            .zip(prefix.chars())
+            .take_while(|(a, b)| a == b)
+            .map(|(c, _)| c.len_utf8())
+            .sum();
+        let offset = offset_in(line, field) + matching;
+        ParseError::in_line(index, line, offset, format!("{prefix:?}"))
+    })
+}
+
+/// The byte offset of `field` within `line`, `field` must be a slice of `line`.
+pub fn offset_in(line: &str, field: &str) -> usize {
+    field.as_ptr() as usize - line.as_ptr() as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let err = ParseError::new(Some('x'), "a digit");
+        assert_eq!("expected a digit, found 'x'", err.to_string());
+
+        let err = err.at(3, 5);
+        assert_eq!(
+            "line 3, column 5: expected a digit, found 'x'",
+            err.to_string()
+        );
+
+        let err = err.in_file("input.txt");
+        assert_eq!(
+            "input.txt:3:5: expected a digit, found 'x'",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn test_with_file() {
+        let err = with_file(
+            ParseError::new(None, "a number").at(1, 4).into(),
+            "input.txt",
+        );
+        let err = err.downcast::<ParseError>().unwrap();
+        assert_eq!(Some("input.txt"), err.file());
+        assert_eq!(Some(1), err.line());
+        assert_eq!(Some(4), err.column());
+    }
+
+    #[test]
+    fn test_parse_number() {
+        let line = "12,3x4";
+        let (x, y) = line.split_once(',').unwrap();
+        assert_eq!(Ok(12), parse_number::<i32>(0, line, x));
+
+        let err = parse_number::<i32>(6, line, y).unwrap_err();
+        assert_eq!(Some('x'), err.found());
+        assert_eq!(Some(7), err.line());
+        assert_eq!(Some(5), err.column());
+
+        let line = "1,";
+        let (_, y) = line.split_once(',').unwrap();
+        let err = parse_number::<i32>(0, line, y).unwrap_err();
+        assert_eq!(None, err.found());
+
+        let line = "7,300";
+        let (_, y) = line.split_once(',').unwrap();
+        let err = parse_number::<u8>(0, line, y).unwrap_err();
+        assert_eq!((Some('3'), Some(3)), (err.found(), err.column()));
+        assert!(err.to_string().contains("fits into u8"));
+
+        let line = "-4";
+        let err = parse_number::<usize>(0, line, line).unwrap_err();
+        assert_eq!((Some('-'), Some(1)), (err.found(), err.column()));
+        assert_eq!(Ok(-4), parse_number::<i32>(0, line, line));
+
+        let err = parse_number::<i32>(0, "-", "-").unwrap_err();
+        assert_eq!(None, err.found());
+    }
+
+    #[test]
+    fn test_expect_prefix() {
+        let line = "p=0,4 v=3,-3";
+        assert_eq!(Ok("0,4 v=3,-3"), expect_prefix(0, line, line, "p="));
+
+        let (_, velocity) = line.split_once(' ').unwrap();
+        let err = expect_prefix(2, line, velocity, "w=").unwrap_err();
+        assert_eq!(Some('v'), err.found());
+        assert_eq!((Some(3), Some(7)), (err.line(), err.column()));
+
+        let err = expect_prefix(0, "Button", "Button", "Button A").unwrap_err();
+        assert_eq!(None, err.found());
+    }
+}
@@ -0,0 +1,217 @@
+use crate::{
+    grid::{Grid, Position},
+    pathfinding::bfs,
+    solution, Solution,
+};
+use anyhow::{ensure, Result};
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+use std::fmt::Display;
+
+/// A generated puzzle input with the answers the generator planted, if any.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Generated {
+    pub input: String,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Generated {
+    pub fn new(input: String) -> Generated {
+        Generated {
+            input,
+            part1: None,
+            part2: None,
+        }
+    }
+
+    pub fn with_part1(mut self, answer: impl Display) -> Generated {
+        self.part1 = Some(answer.to_string());
+        self
+    }
+
+    pub fn with_part2(mut self, answer: impl Display) -> Generated {
+        self.part2 = Some(answer.to_string());
+        self
+    }
+
+    /// The planted answer of `part`, `None` if the generator doesn't know it.
+    pub fn answer(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            2 => self.part2.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+/// Creates random but valid puzzle inputs of a day.
+pub trait Generator {
+    /// The size of the actual puzzle input.
+    const SIZE: usize;
+
+    /// An input scaled by `size`, what the size counts depends on the day,
+    /// like the number of lines or the side of a map.
+    fn generate(rng: &mut StdRng, size: usize) -> Generated;
+}
+
+/// The input of `G` for `seed`, the same seed always creates the same input.
+pub fn generate<G: Generator>(seed: u64, size: usize) -> Generated {
+    G::generate(&mut StdRng::seed_from_u64(seed), size)
+}
+
+/// Generates the input of `seed` and solves every part with a planted answer,
+/// fails if the input is invalid or an answer differs from the planted one.
+pub fn check<S: Solution + Generator>(seed: u64, size: usize) -> Result<Generated> {
+    let generated = generate::<S>(seed, size);
+    S::parse(&generated.input)?;
+
+    for part in [1, 2] {
+        if let Some(planted) = generated.answer(part) {
+            let answer = solution::solve::<S>(part, &generated.input)?;
+            ensure!(
+                answer == planted,
+                "Part {part} answered {answer}, the planted answer is {planted}"
+            );
+        }
+    }
+
+    Ok(generated)
+}
+
+/// A perfect maze, there is exactly one path between any two open cells.
+///
+/// Walls are `true`. The open cells have odd coordinates or connect two of
+/// them, so both sides are rounded up to an odd number of at least three.
+pub fn maze(rng: &mut impl Rng, width: usize, height: usize) -> Grid<bool> {
+    let width = width.max(3) | 1;
+    let height = height.max(3) | 1;
+    let mut maze = Grid::filled(width, height, true);
+
+    // depth first search that knocks down the wall to every unvisited cell
+    maze[(1, 1)] = false;
+    let mut stack: Vec<Position> = vec![(1, 1)];
+    while let Some(&(x, y)) = stack.last() {
+        let mut next: Vec<Position> = [(0, -2), (2, 0), (0, 2), (-2, 0)]
+            .into_iter()
+            .filter_map(|(dx, dy)| Some((x.checked_add_signed(dx)?, y.checked_add_signed(dy)?)))
+            .filter(|&(nx, ny)| nx < width - 1 && ny < height - 1 && maze[(nx, ny)])
+            .collect();
+        next.shuffle(rng);
+
+        match next.first() {
+            Some(&(nx, ny)) => {
+                maze[((x + nx) / 2, (y + ny) / 2)] = false;
+                maze[(nx, ny)] = false;
+                stack.push((nx, ny));
+            }
+            None => {
+                stack.pop();
+            }
+        }
+    }
+
+    maze
+}
+
+/// One of the shortest paths from `start` to `end` over the open cells,
+/// including both ends.
+pub fn shortest_path(maze: &Grid<bool>, start: Position, end: Position) -> Option<Vec<Position>> {
+    let paths = bfs([start], |&position| {
+        maze.neighbors4(position)
+            .filter(|&neighbor| !maze[neighbor])
+    });
+    paths.path_to(&end)
+}
+
+/// Renders a maze with `#` for walls and `.` for open cells, `start` and `end`
+/// are marked with `S` and `E`.
+pub fn render_maze(maze: &Grid<bool>, start: Position, end: Position) -> String {
+    let mut data = String::new();
+    for (y, row) in maze.rows().enumerate() {
+        for (x, &wall) in row.iter().enumerate() {
+            data.push(match (x, y) {
+                position if position == start => 'S',
+                position if position == end => 'E',
+                _ if wall => '#',
+                _ => '.',
+            });
+        }
+        data.push('\n');
+    }
+    data
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Numbers;
+
+    impl Generator for Numbers {
+        const SIZE: usize = 10;
+
+        fn generate(rng: &mut StdRng, size: usize) -> Generated {
+            let numbers: Vec<u32> = (0..size).map(|_| rng.gen_range(0..100)).collect();
+            let sum: u32 = numbers.iter().sum();
+            let lines: Vec<String> = numbers.iter().map(u32::to_string).collect();
+            Generated::new(lines.join("\n")).with_part1(sum)
+        }
+    }
+
+    #[test]
+    fn test_generate_is_seeded() {
+        let generated = generate::<Numbers>(7, 10);
+        assert_eq!(generated, generate::<Numbers>(7, 10));
+        assert_ne!(generated, generate::<Numbers>(8, 10));
+        assert_eq!(10, generated.input.lines().count());
+        assert!(generated.answer(1).is_some());
+        assert_eq!(None, generated.answer(2));
+    }
+
+    impl Solution for Numbers {
+        type Input = Vec<u32>;
+
+        fn parse(data: &str) -> Result<Vec<u32>> {
+            Ok(data.lines().map(str::parse).collect::<Result<_, _>>()?)
+        }
+
+        fn part1(input: &Vec<u32>) -> Result<impl Display> {
+            Ok(input.iter().sum::<u32>())
+        }
+
+        fn part2(input: &Vec<u32>) -> Result<impl Display> {
+            Ok(input.len())
+        }
+    }
+
+    #[test]
+    fn test_check() {
+        let result = check::<Numbers>(3, Numbers::SIZE);
+        assert!(result.is_ok());
+        assert_eq!(generate::<Numbers>(3, Numbers::SIZE), result.unwrap())
+    }
+
+    #[test]
+    fn test_maze() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let maze = maze(&mut rng, 20, 10);
+        assert_eq!((21, 11), (maze.width(), maze.height()));
+
+        // a perfect maze is a tree, it has one passage less than it has rooms
+        let rooms = maze.positions().filter(|&(x, y)| x % 2 == 1 && y % 2 == 1);
+        let open = maze.cells().filter(|wall| !**wall).count();
+        assert_eq!(2 * rooms.count() - 1, open);
+
+        let path = shortest_path(&maze, (1, 1), (19, 9)).unwrap();
+        assert_eq!(Some(&(1, 1)), path.first());
+        assert_eq!(Some(&(19, 9)), path.last());
+        assert!(path
+            .windows(2)
+            .all(|step| { step[0].0.abs_diff(step[1].0) + step[0].1.abs_diff(step[1].1) == 1 }));
+
+        let data = render_maze(&maze, (1, 1), (19, 9));
+        assert_eq!(11, data.lines().count());
+        assert!(data.starts_with("#####################\n#S"));
+        assert_eq!(Some('E'), data.lines().nth(9).unwrap().chars().nth(19));
+    }
+}
@@ -0,0 +1,346 @@
+use crate::{ParseError, Point};
+use anyhow::{bail, Error, Result};
+use std::{
+    convert::Infallible,
+    fmt::{self, Display, Formatter},
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+/// A position on a grid as `(x, y)`, `x` is the column and `y` the row.
+pub type Position = (usize, usize);
+
+/// A rectangular map of cells stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    data: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid from cells stored row by row.
+    pub fn new(width: usize, height: usize, data: Vec<T>) -> Result<Grid<T>> {
+        if data.len() != width * height {
+            bail!(
+                "Expected {} cells for a {width}x{height} grid, found {}",
+                width * height,
+                data.len()
+            );
+        }
+
+        Ok(Grid {
+            data,
+            width,
+            height,
+        })
+    }
+
+    /// Creates a grid with every cell set to `value`.
+    pub fn filled(width: usize, height: usize, value: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            data: vec![value; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Parses a character map, converting every character with `cell`.
+    pub fn parse_with(data: &str, mut cell: impl FnMut(char) -> T) -> Result<Grid<T>> {
+        Grid::try_parse_with(data, |c| Ok::<_, Infallible>(cell(c)))
+    }
+
+    /// Parses a character map with a fallible `cell` conversion, the line and
+    /// column of an invalid character are added to its [`ParseError`].
+    pub fn try_parse_with<E>(
+        data: &str,
+        mut cell: impl FnMut(char) -> Result<T, E>,
+    ) -> Result<Grid<T>>
+    where
+        ParseError: From<E>,
+    {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+
+        for (y, line) in data.lines().enumerate() {
+            if line.is_empty() {
+                continue;
+            }
+
+            let length = line.chars().count();
+            match width {
+                None => width = Some(length),
+                Some(width) if width != length => {
+                    bail!("Line {} has {length} cells, expected {width}", y + 1)
+                }
+                Some(_) => {}
+            }
+
+            for (x, c) in line.chars().enumerate() {
+                let value = cell(c).map_err(|err| ParseError::from(err).at(y + 1, x + 1))?;
+                cells.push(value);
+            }
+            height += 1;
+        }
+
+        Grid::new(width.unwrap_or_default(), height, cells)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Checks if a possibly negative coordinate lies within the grid.
+    pub fn contains<S: TryInto<usize>>(&self, x: S, y: S) -> bool {
+        match (x.try_into(), y.try_into()) {
+            (Ok(x), Ok(y)) => x < self.width && y < self.height,
+            _ => false,
+        }
+    }
+
+    pub fn get(&self, position: impl Into<Position>) -> Option<&T> {
+        let (x, y) = position.into();
+        if x < self.width && y < self.height {
+            Some(&self.data[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, position: impl Into<Position>) -> Option<&mut T> {
+        let (x, y) = position.into();
+        if x < self.width && y < self.height {
+            Some(&mut self.data[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    /// Like [`Grid::get`] but for signed coordinates that may lie outside of the grid.
+    pub fn get_signed<S: TryInto<usize>>(&self, x: S, y: S) -> Option<&T> {
+        self.get((x.try_into().ok()?, y.try_into().ok()?))
+    }
+
+    /// All positions, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// All cells together with their position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.data.iter())
+    }
+
+    pub fn cells(&self) -> impl Iterator<Item = &T> {
+        self.data.iter()
+    }
+
+    /// The position of the first cell equal to `value`.
+    pub fn find(&self, value: &T) -> Option<Position>
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .find(|(_, cell)| *cell == value)
+            .map(|(position, _)| position)
+    }
+
+    /// The positions of all cells equal to `value`.
+    pub fn positions_of<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Position> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(position, _)| position)
+    }
+
+    /// The up to 4 orthogonal neighbors within the grid.
+    pub fn neighbors4(&self, position: impl Into<Position>) -> impl Iterator<Item = Position> + '_ {
+        self.offsets(position.into(), &Point::<isize>::ORTHOGONAL)
+    }
+
+    /// The up to 8 orthogonal and diagonal neighbors within the grid.
+    pub fn neighbors8(&self, position: impl Into<Position>) -> impl Iterator<Item = Position> + '_ {
+        self.offsets(position.into(), &Point::<isize>::ADJACENT)
+    }
+
+    fn offsets<'a>(
+        &'a self,
+        position: Position,
+        offsets: &'a [Point<isize>],
+    ) -> impl Iterator<Item = Position> + 'a {
+        let position = Point::from(position);
+        offsets.iter().filter_map(move |offset| {
+            let next = position.checked_add_signed(*offset)?;
+            (next.x < self.width && next.y < self.height).then_some(next.into())
+        })
+    }
+
+    /// The cells of the row `y`, panics if the row is outside of the grid.
+    pub fn row(&self, y: usize) -> &[T] {
+        assert!(y < self.height, "row {y} is outside of the grid");
+        &self.data[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.data.chunks(self.width.max(1))
+    }
+
+    /// The cells of the column `x` from top to bottom, panics if the column
+    /// is outside of the grid.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {x} is outside of the grid");
+        self.data.iter().skip(x).step_by(self.width.max(1))
+    }
+
+    /// Creates a grid of the same size with every cell converted by `f`.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            data: self.data.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+impl<T, P: Into<Position>> Index<P> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: P) -> &T {
+        let (x, y) = position.into();
+        assert!(
+            x < self.width && y < self.height,
+            "({x}, {y}) is outside of the grid"
+        );
+        &self.data[y * self.width + x]
+    }
+}
+
+impl<T, P: Into<Position>> IndexMut<P> for Grid<T> {
+    fn index_mut(&mut self, position: P) -> &mut T {
+        let (x, y) = position.into();
+        assert!(
+            x < self.width && y < self.height,
+            "({x}, {y}) is outside of the grid"
+        );
+        &mut self.data[y * self.width + x]
+    }
+}
+
+impl<T> FromStr for Grid<T>
+where
+    T: TryFrom<char>,
+    ParseError: From<T::Error>,
+{
+    type Err = Error;
+
+    fn from_str(data: &str) -> Result<Grid<T>> {
+        Grid::try_parse_with(data, T::try_from)
+    }
+}
+
+impl<T: Copy + Into<char>> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            let line: String = row.iter().map(|cell| (*cell).into()).collect();
+            writeln!(f, "{line}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        "abc\ndef\n".parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = grid();
+        assert_eq!(3, grid.width());
+        assert_eq!(2, grid.height());
+        assert_eq!('f', grid[(2, 1)]);
+        assert_eq!('d', grid[Point::new(0, 1)]);
+        assert_eq!(Some(&'b'), grid.get((1, 0)));
+        assert_eq!(None, grid.get((3, 0)));
+        assert_eq!(None, grid.get_signed(-1, 0));
+    }
+
+    #[test]
+    fn test_parse_ragged_lines() {
+        let result: Result<Grid<char>> = "abc\nde\n".parse();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_parse_invalid_cell() {
+        let result = Grid::try_parse_with("01\n2x\n", |c| {
+            c.to_digit(10).ok_or(ParseError::new(Some(c), "a digit"))
+        });
+        let err = result.unwrap_err().downcast::<ParseError>().unwrap();
+        assert_eq!(Some('x'), err.found());
+        assert_eq!(Some(2), err.line());
+        assert_eq!(Some(2), err.column());
+    }
+
+    #[test]
+    fn test_find() {
+        let grid = grid();
+        assert_eq!(Some((1, 1)), grid.find(&'e'));
+        assert_eq!(None, grid.find(&'x'));
+        assert_eq!(vec![(0, 0)], grid.positions_of(&'a').collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = grid();
+        assert_eq!(
+            vec![(1, 0), (0, 1)],
+            grid.neighbors4((0, 0)).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![(1, 0), (1, 1), (0, 1)],
+            grid.neighbors8((0, 0)).collect::<Vec<_>>()
+        );
+        assert_eq!(5, grid.neighbors8((1, 1)).count());
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = grid();
+        assert_eq!(&['d', 'e', 'f'], grid.row(1));
+        assert_eq!(vec![&'b', &'e'], grid.column(1).collect::<Vec<_>>());
+        assert_eq!(2, grid.rows().count());
+    }
+
+    #[test]
+    #[should_panic(expected = "column 3 is outside of the grid")]
+    fn test_column_outside() {
+        let _ = grid().column(3);
+    }
+
+    #[test]
+    #[should_panic(expected = "row 2 is outside of the grid")]
+    fn test_row_outside() {
+        grid().row(2);
+    }
+
+    #[test]
+    fn test_display() {
+        let grid = grid().map(|c| c.to_ascii_uppercase());
+        assert_eq!("ABC\nDEF\n", grid.to_string());
+    }
+}
@@ -0,0 +1,79 @@
+use anyhow::{Context, Result};
+use std::{fs::read_to_string, io::BufRead};
+
+/// Reads the whole puzzle input file into a [`normalize`]d string.
+pub fn read_input(filename: &str) -> Result<String> {
+    let data = read_to_string(filename)
+        .with_context(|| format!("Failed to read input file {filename}"))?;
+    Ok(normalize(&data))
+}
+
+/// Reads the whole puzzle input from a reader, e.g. stdin, into a
+/// [`normalize`]d string.
+pub fn read_input_from(mut reader: impl BufRead) -> Result<String> {
+    let mut data = String::new();
+    reader
+        .read_to_string(&mut data)
+        .context("Failed to read the puzzle input")?;
+    Ok(normalize(&data))
+}
+
+/// Brings a puzzle input into the shape the parsers expect, like a file
+/// saved on Unix: without a byte order mark, with `\n` line endings, without
+/// trailing whitespace on any line and with exactly one newline at the end.
+///
+/// Blank lines at the end are removed, all other lines keep their number so
+/// parse errors point to the same line as in the original input.
+pub fn normalize(data: &str) -> String {
+    let data = data.strip_prefix('\u{feff}').unwrap_or(data);
+
+    let mut normalized = String::with_capacity(data.len() + 1);
+    for line in data.lines() {
+        normalized.push_str(line.trim_end());
+        normalized.push('\n');
+    }
+
+    let content = normalized.trim_end_matches('\n').len();
+    match content {
+        0 => String::new(),
+        _ => {
+            normalized.truncate(content + 1);
+            normalized
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_missing_file() {
+        let result = read_input("does_not_exist.txt");
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("does_not_exist.txt"))
+    }
+
+    #[test]
+    fn test_read_from() {
+        let result = read_input_from("1 2\n3 4\n".as_bytes());
+        assert!(result.is_ok());
+        assert_eq!("1 2\n3 4\n", result.unwrap())
+    }
+
+    #[test]
+    fn test_normalize() {
+        let clean = "1 2\n\n3 4\n";
+        assert_eq!(clean, normalize(clean));
+        assert_eq!(clean, normalize("1 2\r\n\r\n3 4\r\n"));
+        assert_eq!(clean, normalize("\u{feff}1 2\n\n3 4\n"));
+        assert_eq!(clean, normalize("1 2\n\n3 4"));
+        assert_eq!(clean, normalize("1 2\n\n3 4\n\n\n"));
+        assert_eq!(clean, normalize("1 2  \n \t\n3 4\t\r\n \n"));
+        assert_eq!("  1 2\n", normalize("  1 2"));
+        assert_eq!("", normalize("\u{feff}\r\n\n"));
+    }
+}
@@ -0,0 +1,21 @@
+//! Code shared between the daily puzzle crates.
+
+pub mod answer;
+pub mod answers;
+pub mod bench;
+pub mod direction;
+pub mod error;
+pub mod generate;
+pub mod grid;
+pub mod input;
+pub mod pathfinding;
+pub mod point;
+pub mod record;
+pub mod render;
+pub mod solution;
+
+pub use direction::Direction;
+pub use error::ParseError;
+pub use input::{normalize, read_input, read_input_from};
+pub use point::Point;
+pub use solution::Solution;
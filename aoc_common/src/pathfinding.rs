@@ -0,0 +1,337 @@
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+/// The cheapest costs from the start states to every reached state, together
+/// with all predecessors of a state on one of its cheapest paths.
+#[derive(Debug, Clone)]
+pub struct Paths<N> {
+    distances: HashMap<N, usize>,
+    predecessors: HashMap<N, Vec<N>>,
+    starts: HashSet<N>,
+}
+
+impl<N: Clone + Eq + Hash> Paths<N> {
+    fn new() -> Paths<N> {
+        Paths {
+            distances: HashMap::new(),
+            predecessors: HashMap::new(),
+            starts: HashSet::new(),
+        }
+    }
+
+    fn start(&mut self, node: N) {
+        self.distances.insert(node.clone(), 0);
+        self.starts.insert(node);
+    }
+
+    /// The cost of the cheapest path to `node`, `None` if it wasn't reached.
+    pub fn distance(&self, node: &N) -> Option<usize> {
+        self.distances.get(node).copied()
+    }
+
+    pub fn distances(&self) -> &HashMap<N, usize> {
+        &self.distances
+    }
+
+    /// The states right before `node` on its cheapest paths, empty for the
+    /// start states and states that weren't reached.
+    pub fn predecessors(&self, node: &N) -> &[N] {
+        self.predecessors.get(node).map_or(&[], Vec::as_slice)
+    }
+
+    /// One of the cheapest paths to `end`, including the start and `end`.
+    pub fn path_to(&self, end: &N) -> Option<Vec<N>> {
+        self.distances.get(end)?;
+
+        let mut path = vec![end.clone()];
+        while let Some(previous) = self.predecessors(path.last().unwrap()).first() {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Every state on any of the cheapest paths to one of `ends`.
+    pub fn on_paths_to(&self, ends: impl IntoIterator<Item = N>) -> HashSet<N> {
+        let mut stack: Vec<N> = ends
+            .into_iter()
+            .filter(|end| self.distances.contains_key(end))
+            .collect();
+
+        let mut visited = HashSet::new();
+        while let Some(node) = stack.pop() {
+            if visited.insert(node.clone()) {
+                stack.extend(self.predecessors(&node).iter().cloned());
+            }
+        }
+
+        visited
+    }
+
+    // records `previous` as a way to reach `node` for `distance`,
+    // returns whether the path is cheaper than any path seen before.
+    // Start states and `settled` states only come up again over steps that
+    // cost nothing, recording those could make the predecessors a cycle.
+    fn relax(&mut self, node: &N, previous: &N, distance: usize, settled: &HashSet<N>) -> bool {
+        let known = self.distances.get(node).copied().unwrap_or(usize::MAX);
+        match distance.cmp(&known) {
+            Ordering::Less => {
+                self.distances.insert(node.clone(), distance);
+                self.predecessors
+                    .insert(node.clone(), vec![previous.clone()]);
+                true
+            }
+            Ordering::Equal if self.starts.contains(node) || settled.contains(node) => false,
+            Ordering::Equal => {
+                self.predecessors
+                    .entry(node.clone())
+                    .or_default()
+                    .push(previous.clone());
+                false
+            }
+            Ordering::Greater => false,
+        }
+    }
+}
+
+/// Breadth first search from `starts`, every step costs one.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+) -> Paths<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut paths = Paths::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        paths.start(start.clone());
+        queue.push_back(start);
+    }
+
+    // nothing is settled: a node reached again for the same cost gets another
+    // predecessor, with steps of one it can't be the start or a node before it
+    let settled = HashSet::new();
+    while let Some(node) = queue.pop_front() {
+        let distance = paths.distances[&node] + 1;
+        for next in successors(&node) {
+            if paths.relax(&next, &node, distance, &settled) {
+                queue.push_back(next);
+            }
+        }
+    }
+
+    paths
+}
+
+/// Dijkstra's algorithm from `starts`, `successors` returns the reachable
+/// states of a state together with the cost of the step.
+pub fn dijkstra<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+) -> Paths<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    let mut paths = Paths::new();
+    let mut queue = Queue::default();
+    for start in starts {
+        paths.start(start.clone());
+        queue.push(0, start);
+    }
+
+    let mut done = HashSet::new();
+    while let Some((distance, node)) = queue.pop() {
+        if !done.insert(node.clone()) {
+            continue;
+        }
+
+        for (next, cost) in successors(&node) {
+            if paths.relax(&next, &node, distance + cost, &done) {
+                queue.push(distance + cost, next);
+            }
+        }
+    }
+
+    paths
+}
+
+/// A* search from `start` to the first state that `is_goal`, returns the path
+/// including both ends and its cost.
+///
+/// `heuristic` estimates the remaining cost of a state, it must never be more
+/// than the actual cost or the path might not be the cheapest one.
+pub fn astar<N, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> usize,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, usize)>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    let mut paths = Paths::new();
+    let mut queue = Queue::default();
+    paths.start(start.clone());
+    queue.push(heuristic(&start), start);
+
+    let mut done = HashSet::new();
+    while let Some((_, node)) = queue.pop() {
+        let distance = paths.distances[&node];
+        if is_goal(&node) {
+            return Some((paths.path_to(&node)?, distance));
+        }
+        if !done.insert(node.clone()) {
+            continue;
+        }
+
+        for (next, cost) in successors(&node) {
+            if paths.relax(&next, &node, distance + cost, &done) {
+                let estimate = distance + cost + heuristic(&next);
+                queue.push(estimate, next);
+            }
+        }
+    }
+
+    None
+}
+
+// a min-heap of states by priority, the states don't need to be ordered
+// because the heap only compares the priority and the insertion order
+struct Queue<N> {
+    heap: BinaryHeap<Reverse<(usize, usize)>>,
+    nodes: Vec<Option<N>>,
+}
+
+impl<N> Default for Queue<N> {
+    fn default() -> Queue<N> {
+        Queue {
+            heap: BinaryHeap::new(),
+            nodes: Vec::new(),
+        }
+    }
+}
+
+impl<N> Queue<N> {
+    fn push(&mut self, priority: usize, node: N) {
+        self.heap.push(Reverse((priority, self.nodes.len())));
+        self.nodes.push(Some(node));
+    }
+
+    fn pop(&mut self) -> Option<(usize, N)> {
+        let Reverse((priority, index)) = self.heap.pop()?;
+        Some((priority, self.nodes[index].take()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::{Grid, Position};
+
+    // two routes of the same length around the wall in the middle
+    fn grid() -> Grid<char> {
+        "...\n.#.\n...".parse().unwrap()
+    }
+
+    fn open(grid: &Grid<char>) -> impl FnMut(&Position) -> Vec<Position> + '_ {
+        |&position| {
+            grid.neighbors4(position)
+                .filter(|&neighbor| grid[neighbor] == '.')
+                .collect()
+        }
+    }
+
+    #[test]
+    fn test_bfs() {
+        let grid = grid();
+        let paths = bfs([(0, 0)], open(&grid));
+        assert_eq!(Some(4), paths.distance(&(2, 2)));
+        assert_eq!(None, paths.distance(&(1, 1)));
+        assert_eq!(8, paths.distances().len());
+
+        let mut predecessors = paths.predecessors(&(2, 2)).to_vec();
+        predecessors.sort();
+        assert_eq!(vec![(1, 2), (2, 1)], predecessors);
+
+        let path = paths.path_to(&(2, 2)).unwrap();
+        assert_eq!(5, path.len());
+        assert_eq!((Some(&(0, 0)), Some(&(2, 2))), (path.first(), path.last()));
+        assert_eq!(None, paths.path_to(&(1, 1)));
+        assert_eq!(8, paths.on_paths_to([(2, 2)]).len());
+        assert_eq!(3, paths.on_paths_to([(2, 0)]).len());
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        // entering the left column is expensive, so the route on the right wins
+        let grid = grid();
+        let mut neighbors = open(&grid);
+        let paths = dijkstra([(0, 0)], |position| {
+            neighbors(position)
+                .into_iter()
+                .map(|next: Position| (next, if next.0 == 0 { 10 } else { 1 }))
+                .collect::<Vec<_>>()
+        });
+
+        assert_eq!(Some(4), paths.distance(&(2, 2)));
+        assert_eq!(
+            Some(vec![(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)]),
+            paths.path_to(&(2, 2))
+        );
+        assert_eq!(5, paths.on_paths_to([(2, 2)]).len());
+    }
+
+    #[test]
+    fn test_astar() {
+        let grid: Grid<char> = ".....\n.###.\n...#.\n.#...".parse().unwrap();
+        let mut neighbors = open(&grid);
+        let steps = |position: &Position| {
+            neighbors(position)
+                .into_iter()
+                .map(|next| (next, 1))
+                .collect::<Vec<_>>()
+        };
+        let distance = |&(x, y): &Position| (4 - x) + (3 - y);
+
+        let result = astar((0, 0), steps, distance, |&position| position == (4, 3));
+        assert!(result.is_some());
+
+        let (path, cost) = result.unwrap();
+        assert_eq!(7, cost);
+        assert_eq!(8, path.len());
+        assert!(astar(
+            (0, 0),
+            |_| Vec::new(),
+            |_| 0,
+            |&position| position == (4, 3)
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn test_zero_costs() {
+        // 0 and 1 as well as 1 and 2 are connected for free in both directions
+        let steps = |&node: &usize| match node {
+            0 => vec![(1, 0)],
+            1 => vec![(0, 0), (2, 0)],
+            2 => vec![(1, 0), (3, 1)],
+            _ => vec![],
+        };
+
+        let paths = dijkstra([0], steps);
+        assert_eq!(Some(1), paths.distance(&3));
+        assert!(paths.predecessors(&0).is_empty());
+        assert_eq!(Some(vec![0, 1, 2, 3]), paths.path_to(&3));
+        assert_eq!(4, paths.on_paths_to([3]).len());
+
+        let result = astar(0, steps, |_| 0, |&node| node == 3);
+        assert_eq!(Some((vec![0, 1, 2, 3], 1)), result);
+    }
+}
@@ -0,0 +1,242 @@
+use crate::Direction;
+use std::{
+    fmt::{self, Display, Formatter},
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+/// A point or offset on a map where `y` grows downwards.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point<T> {
+    pub const fn new(x: T, y: T) -> Point<T> {
+        Point { x, y }
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Point<T>;
+
+    fn add(self, other: Point<T>) -> Point<T> {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Point<T>;
+
+    fn sub(self, other: Point<T>) -> Point<T> {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Mul<T> for Point<T> {
+    type Output = Point<T>;
+
+    fn mul(self, factor: T) -> Point<T> {
+        Point::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point<T> {
+    type Output = Point<T>;
+
+    fn neg(self) -> Point<T> {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point<T> {
+    fn add_assign(&mut self, other: Point<T>) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Point<T> {
+    fn sub_assign(&mut self, other: Point<T>) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+impl<T> From<(T, T)> for Point<T> {
+    fn from((x, y): (T, T)) -> Point<T> {
+        Point::new(x, y)
+    }
+}
+
+impl<T> From<Point<T>> for (T, T) {
+    fn from(point: Point<T>) -> (T, T) {
+        (point.x, point.y)
+    }
+}
+
+/// Formats the point the way the puzzles write coordinates, `x,y`.
+impl<T: Display> Display for Point<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+macro_rules! impl_signed_point {
+    ($($t:ty),*) => {$(
+        impl Point<$t> {
+            /// Unit offsets to the 4 orthogonal neighbors, clockwise starting upwards.
+            pub const ORTHOGONAL: [Point<$t>; 4] = [
+                Point::new(0, -1),
+                Point::new(1, 0),
+                Point::new(0, 1),
+                Point::new(-1, 0),
+            ];
+
+            /// Unit offsets to all 8 neighbors, clockwise starting upwards.
+            pub const ADJACENT: [Point<$t>; 8] = [
+                Point::new(0, -1),
+                Point::new(1, -1),
+                Point::new(1, 0),
+                Point::new(1, 1),
+                Point::new(0, 1),
+                Point::new(-1, 1),
+                Point::new(-1, 0),
+                Point::new(-1, -1),
+            ];
+
+            pub fn manhattan(self, other: Point<$t>) -> $t {
+                (self.x - other.x).abs() + (self.y - other.y).abs()
+            }
+
+            pub fn step(self, direction: Direction) -> Point<$t> {
+                self + Point::from(direction)
+            }
+
+            /// Steps in `direction` unless that leaves a `width` x `height` map.
+            pub fn step_within(
+                self,
+                direction: Direction,
+                width: usize,
+                height: usize,
+            ) -> Option<Point<$t>> {
+                let next = self.step(direction);
+                next.within(width, height).then_some(next)
+            }
+
+            pub fn within(self, width: usize, height: usize) -> bool {
+                self.to_unsigned()
+                    .is_some_and(|point| point.x < width && point.y < height)
+            }
+
+            /// The point as map coordinates, `None` if it has a negative component.
+            pub fn to_unsigned(self) -> Option<Point<usize>> {
+                Some(Point::new(self.x.try_into().ok()?, self.y.try_into().ok()?))
+            }
+
+            /// Rotates the offset by 90 degrees clockwise.
+            pub fn turn_right(self) -> Point<$t> {
+                Point::new(-self.y, self.x)
+            }
+
+            /// Rotates the offset by 90 degrees counterclockwise.
+            pub fn turn_left(self) -> Point<$t> {
+                Point::new(self.y, -self.x)
+            }
+
+            pub fn reverse(self) -> Point<$t> {
+                -self
+            }
+
+            pub fn neighbors4(self) -> impl Iterator<Item = Point<$t>> {
+                Point::<$t>::ORTHOGONAL.into_iter().map(move |offset| self + offset)
+            }
+
+            pub fn neighbors8(self) -> impl Iterator<Item = Point<$t>> {
+                Point::<$t>::ADJACENT.into_iter().map(move |offset| self + offset)
+            }
+        }
+
+        impl From<Direction> for Point<$t> {
+            fn from(direction: Direction) -> Point<$t> {
+                Point::<$t>::ORTHOGONAL[direction as usize]
+            }
+        }
+    )*};
+}
+
+impl_signed_point!(i32, i64, isize);
+
+impl Point<usize> {
+    pub fn manhattan(self, other: Point<usize>) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// Adds a signed offset, `None` if a component would become negative.
+    pub fn checked_add_signed(self, offset: Point<isize>) -> Option<Point<usize>> {
+        Some(Point::new(
+            self.x.checked_add_signed(offset.x)?,
+            self.y.checked_add_signed(offset.y)?,
+        ))
+    }
+
+    /// Steps in `direction` unless that leaves a `width` x `height` map.
+    pub fn step_within(
+        self,
+        direction: Direction,
+        width: usize,
+        height: usize,
+    ) -> Option<Point<usize>> {
+        self.checked_add_signed(direction.into())
+            .filter(|next| next.x < width && next.y < height)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Point::new(3i32, -2);
+        let b = Point::new(1, 4);
+        assert_eq!(Point::new(4, 2), a + b);
+        assert_eq!(Point::new(2, -6), a - b);
+        assert_eq!(Point::new(6, -4), a * 2);
+        assert_eq!(8, a.manhattan(b));
+        assert_eq!("3,-2", a.to_string());
+    }
+
+    #[test]
+    fn test_rotations() {
+        let up = Point::<i32>::from(Direction::Up);
+        assert_eq!(Point::from(Direction::Right), up.turn_right());
+        assert_eq!(Point::from(Direction::Left), up.turn_left());
+        assert_eq!(Point::from(Direction::Down), up.reverse());
+    }
+
+    #[test]
+    fn test_step_within() {
+        let corner = Point::new(0usize, 0);
+        assert_eq!(None, corner.step_within(Direction::Up, 3, 3));
+        assert_eq!(None, corner.step_within(Direction::Left, 3, 3));
+        assert_eq!(
+            Some(Point::new(1, 0)),
+            corner.step_within(Direction::Right, 3, 3)
+        );
+        assert_eq!(None, Point::new(2i32, 2).step_within(Direction::Down, 3, 3));
+        assert_eq!(
+            Some(Point::new(2, 1)),
+            Point::new(2i32, 2).step_within(Direction::Up, 3, 3)
+        );
+    }
+
+    #[test]
+    fn test_neighbors() {
+        assert_eq!(4, Point::new(0i64, 0).neighbors4().count());
+        assert!(Point::new(0i64, 0)
+            .neighbors8()
+            .any(|point| point == Point::new(-1, -1)));
+        assert_eq!(None, Point::new(-1i32, 0).to_unsigned());
+    }
+}
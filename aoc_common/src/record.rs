@@ -0,0 +1,294 @@
+use crate::{
+    input::normalize,
+    render::{encode, pixels, Canvas, Color, Format},
+    Solution,
+};
+use anyhow::{ensure, Context, Result};
+use std::{
+    collections::HashMap,
+    fs::{create_dir_all, write},
+    path::Path,
+};
+
+/// Collects snapshots of a simulation while it runs.
+///
+/// The simulation reports every step, the recorder only draws the steps it
+/// keeps: every `every`th step after the skipped ones, up to `limit` frames.
+/// The final state is always kept as the last frame.
+#[derive(Debug, Clone)]
+pub struct Recorder {
+    skip: usize,
+    every: usize,
+    limit: usize,
+    steps: usize,
+    last_recorded: Option<usize>,
+    frames: Vec<Canvas>,
+}
+
+impl Recorder {
+    /// Keeps every `every`th step, starting with the first one.
+    pub fn new(every: usize) -> Recorder {
+        Recorder {
+            skip: 0,
+            every: every.max(1),
+            limit: usize::MAX,
+            steps: 0,
+            last_recorded: None,
+            frames: Vec::new(),
+        }
+    }
+
+    /// A recorder that never draws anything, for solving without recording.
+    pub fn off() -> Recorder {
+        Recorder {
+            limit: 0,
+            ..Recorder::new(1)
+        }
+    }
+
+    /// Skips the first `steps` steps.
+    pub fn skip(mut self, steps: usize) -> Recorder {
+        self.skip = steps;
+        self
+    }
+
+    /// Keeps at most `limit` frames before the final one.
+    pub fn limit(mut self, limit: usize) -> Recorder {
+        self.limit = limit;
+        self
+    }
+
+    pub fn is_off(&self) -> bool {
+        self.limit == 0
+    }
+
+    /// Reports the next step of the simulation, `draw` is only called if
+    /// the step is kept.
+    pub fn step(&mut self, draw: impl FnOnce() -> Canvas) {
+        let step = self.steps;
+        self.steps += 1;
+
+        let kept = step >= self.skip && (step - self.skip).is_multiple_of(self.every);
+        if kept && self.frames.len() < self.limit {
+            self.frames.push(draw());
+            self.last_recorded = Some(step);
+        }
+    }
+
+    /// Reports the end of the simulation, `draw` is the state after the last
+    /// step and only called if that step wasn't kept already.
+    pub fn finish(&mut self, draw: impl FnOnce() -> Canvas) {
+        if !self.is_off() && self.last_recorded.map(|step| step + 1) != Some(self.steps) {
+            self.frames.push(draw());
+            self.last_recorded = self.steps.checked_sub(1);
+        }
+    }
+
+    /// The number of steps reported so far.
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    pub fn frames(&self) -> &[Canvas] {
+        &self.frames
+    }
+}
+
+/// A day with a step-based simulation that can be watched, like the robots
+/// of day 14 or the guard of day 6.
+pub trait Record: Solution {
+    /// Runs the simulation of the day and reports every step to `recorder`.
+    fn record(input: &Self::Input, recorder: &mut Recorder) -> Result<()>;
+}
+
+/// Parses the [`normalize`]d `data` and records the simulation of the day `R`.
+pub fn record<R: Record>(data: &str, recorder: &mut Recorder) -> Result<()> {
+    R::record(&R::parse(&normalize(data))?, recorder)
+}
+
+/// Encodes `frames` as an endlessly looping GIF animation, every frame is shown
+/// for `delay` hundredths of a second.
+pub fn gif(frames: &[Canvas], scale: usize, delay: u16) -> Result<Vec<u8>> {
+    let first = frames.first().context("No frames were recorded")?;
+    ensure!(
+        frames
+            .iter()
+            .all(|frame| (frame.width(), frame.height()) == (first.width(), first.height())),
+        "The frames differ in size"
+    );
+
+    let (width, height, _) = pixels(first, scale);
+    let (width, height) = (
+        u16::try_from(width).context("The animation is too wide")?,
+        u16::try_from(height).context("The animation is too high")?,
+    );
+
+    // the frames share a palette if they don't use too many colors, which is
+    // much faster than quantizing every frame
+    let colors = palette(frames);
+    let mut global = vec![0; colors.len() * 3];
+    for (color, &index) in &colors {
+        global[index as usize * 3..][..3].copy_from_slice(color);
+    }
+
+    let mut image = Vec::new();
+    {
+        let mut encoder = gif::Encoder::new(&mut image, width, height, &global)?;
+        encoder.set_repeat(gif::Repeat::Infinite)?;
+
+        for frame in frames {
+            let (_, _, rgb) = pixels(frame, scale);
+            let mut frame = match colors.is_empty() {
+                true => gif::Frame::from_rgb_speed(width, height, &rgb, 10),
+                false => {
+                    let indices: Vec<u8> = rgb.chunks(3).map(|pixel| colors[pixel]).collect();
+                    gif::Frame {
+                        width,
+                        height,
+                        buffer: indices.into(),
+                        ..gif::Frame::default()
+                    }
+                }
+            };
+            frame.delay = delay;
+            encoder.write_frame(&frame)?;
+        }
+    }
+
+    Ok(image)
+}
+
+// the index of every color in all frames, empty if there are more colors
+// than a GIF palette can hold
+fn palette(frames: &[Canvas]) -> HashMap<Color, u8> {
+    let mut colors = HashMap::new();
+    for cell in frames.iter().flat_map(|frame| frame.cells()) {
+        if !colors.contains_key(&cell.color) {
+            let Ok(index) = u8::try_from(colors.len()) else {
+                return HashMap::new();
+            };
+            colors.insert(cell.color, index);
+        }
+    }
+    colors
+}
+
+/// Writes every frame to its own numbered file in `directory`, like
+/// `frame_0042.png`, and returns the number of files.
+pub fn write_sequence(
+    frames: &[Canvas],
+    directory: &Path,
+    format: Format,
+    scale: usize,
+) -> Result<usize> {
+    create_dir_all(directory)
+        .with_context(|| format!("Failed to create {}", directory.display()))?;
+
+    let extension = match format {
+        Format::Ascii | Format::Ansi => "txt",
+        Format::Ppm => "ppm",
+        Format::Png => "png",
+    };
+    let digits = frames.len().to_string().len().max(4);
+
+    for (index, frame) in frames.iter().enumerate() {
+        let path = directory.join(format!("frame_{index:0digits$}.{extension}"));
+        write(&path, encode(frame, format, scale)?)
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+    }
+
+    Ok(frames.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        grid::Grid,
+        render::{Cell, BLACK, RED},
+    };
+
+    // a single red cell moving along a row of `width` cells
+    fn frame(width: usize, position: usize) -> Canvas {
+        let mut canvas = Grid::filled(width, 1, Cell::new('.', BLACK));
+        canvas[(position, 0)] = Cell::new('@', RED);
+        canvas
+    }
+
+    fn simulate(recorder: &mut Recorder, steps: usize) {
+        for step in 0..steps {
+            recorder.step(|| frame(steps, step));
+        }
+        recorder.finish(|| frame(steps, steps - 1));
+    }
+
+    fn positions(recorder: &Recorder) -> Vec<usize> {
+        let frames = recorder.frames().iter();
+        frames
+            .map(|frame| frame.row(0).iter().position(|cell| cell.symbol == '@'))
+            .map(Option::unwrap)
+            .collect()
+    }
+
+    #[test]
+    fn test_recorder() {
+        let mut recorder = Recorder::new(1);
+        simulate(&mut recorder, 4);
+        assert_eq!(vec![0, 1, 2, 3], positions(&recorder));
+
+        let mut recorder = Recorder::new(3).skip(1);
+        simulate(&mut recorder, 10);
+        assert_eq!(10, recorder.steps());
+        assert_eq!(vec![1, 4, 7, 9], positions(&recorder));
+
+        let mut recorder = Recorder::new(2).limit(2);
+        simulate(&mut recorder, 10);
+        assert_eq!(vec![0, 2, 9], positions(&recorder));
+
+        let mut recorder = Recorder::off();
+        simulate(&mut recorder, 10);
+        assert!(recorder.is_off());
+        assert!(recorder.frames().is_empty());
+    }
+
+    #[test]
+    fn test_gif() {
+        let frames: Vec<Canvas> = (0..3).map(|position| frame(3, position)).collect();
+        let result = gif(&frames, 2, 10);
+        assert!(result.is_ok());
+
+        let image = result.unwrap();
+        assert!(image.starts_with(b"GIF89a"));
+        let mut options = gif::DecodeOptions::new();
+        options.set_color_output(gif::ColorOutput::RGBA);
+        let mut decoder = options.read_info(image.as_slice()).unwrap();
+        assert_eq!((6, 2), (decoder.width(), decoder.height()));
+
+        // the red cell moves two pixels to the right in every frame
+        let mut count = 0;
+        while let Some(frame) = decoder.read_next_frame().unwrap() {
+            assert_eq!(10, frame.delay);
+            assert_eq!(&RED, &frame.buffer[count * 2 * 4..][..3]);
+            assert_eq!(&BLACK, &frame.buffer[(count * 2 + 2) % 6 * 4..][..3]);
+            count += 1;
+        }
+        assert_eq!(3, count);
+
+        assert!(gif(&[], 2, 10).is_err());
+        assert!(gif(&[frame(3, 0), frame(4, 0)], 2, 10).is_err());
+    }
+
+    #[test]
+    fn test_write_sequence() {
+        let directory = std::env::temp_dir().join(format!("aoc_frames_{}", std::process::id()));
+        let frames: Vec<Canvas> = (0..3).map(|position| frame(3, position)).collect();
+
+        let result = write_sequence(&frames, &directory, Format::Ascii, 1);
+        assert!(result.is_ok());
+        assert_eq!(3, result.unwrap());
+        let last = std::fs::read_to_string(directory.join("frame_0002.txt")).unwrap();
+        assert_eq!("..@\n", last);
+
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}
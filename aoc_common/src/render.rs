@@ -0,0 +1,226 @@
+use crate::{grid::Grid, input::normalize, Solution};
+use anyhow::{Context, Result};
+use std::{fmt::Write, path::Path};
+
+pub type Color = [u8; 3];
+
+pub const BLACK: Color = [0, 0, 0];
+pub const WHITE: Color = [255, 255, 255];
+pub const DARK: Color = [56, 56, 56];
+pub const GREY: Color = [128, 128, 128];
+pub const RED: Color = [220, 50, 47];
+pub const GREEN: Color = [70, 190, 70];
+pub const BLUE: Color = [38, 139, 210];
+pub const YELLOW: Color = [235, 200, 40];
+
+/// How a single cell is drawn, as a character in text and as a color in images.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cell {
+    pub symbol: char,
+    pub color: Color,
+}
+
+impl Cell {
+    pub const fn new(symbol: char, color: Color) -> Cell {
+        Cell { symbol, color }
+    }
+}
+
+impl From<Cell> for char {
+    fn from(cell: Cell) -> char {
+        cell.symbol
+    }
+}
+
+/// A drawn grid-shaped state.
+pub type Canvas = Grid<Cell>;
+
+/// A day that can draw its puzzle state, like the path of a guard or the
+/// regions of a garden. What is drawn is up to the day.
+pub trait Render: Solution {
+    fn render(input: &Self::Input) -> Result<Canvas>;
+}
+
+/// Parses the [`normalize`]d `data` and draws the state of the day `R`.
+pub fn snapshot<R: Render>(data: &str) -> Result<Canvas> {
+    R::render(&R::parse(&normalize(data))?)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// The symbols of the cells.
+    Ascii,
+    /// The symbols of the cells colored for a terminal.
+    Ansi,
+    /// A binary PPM image.
+    Ppm,
+    Png,
+}
+
+impl Format {
+    /// The image format of a file by its extension, text files are ASCII.
+    pub fn from_path(path: &Path) -> Option<Format> {
+        match path.extension()?.to_str()? {
+            "txt" => Some(Format::Ascii),
+            "ppm" => Some(Format::Ppm),
+            "png" => Some(Format::Png),
+            _ => None,
+        }
+    }
+}
+
+/// Encodes `canvas` in `format`, images draw every cell as a square of
+/// `scale` pixels.
+pub fn encode(canvas: &Canvas, format: Format, scale: usize) -> Result<Vec<u8>> {
+    match format {
+        Format::Ascii => Ok(ascii(canvas).into_bytes()),
+        Format::Ansi => Ok(ansi(canvas).into_bytes()),
+        Format::Ppm => Ok(ppm(canvas, scale)),
+        Format::Png => png(canvas, scale),
+    }
+}
+
+pub fn ascii(canvas: &Canvas) -> String {
+    canvas.to_string()
+}
+
+/// The symbols in the true colors of their cells.
+pub fn ansi(canvas: &Canvas) -> String {
+    let mut text = String::new();
+    for row in canvas.rows() {
+        for cell in row {
+            let [r, g, b] = cell.color;
+            let _ = write!(text, "\x1b[38;2;{r};{g};{b}m{}", cell.symbol);
+        }
+        text.push_str("\x1b[0m\n");
+    }
+    text
+}
+
+pub fn ppm(canvas: &Canvas, scale: usize) -> Vec<u8> {
+    let (width, height, pixels) = pixels(canvas, scale);
+    let mut image = format!("P6\n{width} {height}\n255\n").into_bytes();
+    image.extend(pixels);
+    image
+}
+
+pub fn png(canvas: &Canvas, scale: usize) -> Result<Vec<u8>> {
+    let (width, height, pixels) = pixels(canvas, scale);
+    let mut image = Vec::new();
+
+    let mut encoder = png::Encoder::new(&mut image, width as u32, height as u32);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder
+        .write_header()
+        .and_then(|mut writer| writer.write_image_data(&pixels))
+        .context("Failed to encode the PNG image")?;
+
+    Ok(image)
+}
+
+// the RGB values of all pixels row by row, every cell is a square of pixels
+pub(crate) fn pixels(canvas: &Canvas, scale: usize) -> (usize, usize, Vec<u8>) {
+    let scale = scale.max(1);
+    let (width, height) = (canvas.width() * scale, canvas.height() * scale);
+
+    let mut pixels = Vec::with_capacity(width * height * 3);
+    for row in canvas.rows() {
+        let line: Vec<u8> = row
+            .iter()
+            .flat_map(|cell| cell.color.repeat(scale))
+            .collect();
+        for _ in 0..scale {
+            pixels.extend(&line);
+        }
+    }
+
+    (width, height, pixels)
+}
+
+/// A color for the `index`th of many things, like regions, consecutive
+/// indices get clearly different hues.
+pub fn palette(index: usize) -> Color {
+    // steps by the golden angle so the hues never repeat
+    let hue = (index as f64 * 137.508) % 360.0;
+    let x = 1.0 - ((hue / 60.0) % 2.0 - 1.0).abs();
+    let (r, g, b) = match hue as u32 / 60 {
+        0 => (1.0, x, 0.0),
+        1 => (x, 1.0, 0.0),
+        2 => (0.0, 1.0, x),
+        3 => (0.0, x, 1.0),
+        4 => (x, 0.0, 1.0),
+        _ => (1.0, 0.0, x),
+    };
+
+    // not fully saturated, pure colors are hard on the eyes
+    [r, g, b].map(|value: f64| (64.0 + value * 176.0) as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn canvas() -> Canvas {
+        let cells = vec![
+            Cell::new('#', WHITE),
+            Cell::new('.', BLACK),
+            Cell::new('@', RED),
+            Cell::new('.', BLACK),
+        ];
+        Grid::new(2, 2, cells).unwrap()
+    }
+
+    #[test]
+    fn test_text() {
+        assert_eq!("#.\n@.\n", ascii(&canvas()));
+
+        let text = ansi(&canvas());
+        assert_eq!(2, text.lines().count());
+        assert!(text.starts_with("\x1b[38;2;255;255;255m#\x1b[38;2;0;0;0m."));
+        assert!(text.ends_with("\x1b[0m\n"));
+    }
+
+    #[test]
+    fn test_ppm() {
+        let image = ppm(&canvas(), 2);
+        let header = b"P6\n4 4\n255\n";
+        assert!(image.starts_with(header));
+        assert_eq!(header.len() + 4 * 4 * 3, image.len());
+
+        // the red cell covers the lower left corner
+        let pixel = |x: usize, y: usize| &image[header.len() + (y * 4 + x) * 3..][..3];
+        assert_eq!(&WHITE, pixel(1, 1));
+        assert_eq!(&BLACK, pixel(2, 1));
+        assert_eq!(&RED, pixel(0, 3));
+    }
+
+    #[test]
+    fn test_png() {
+        let result = png(&canvas(), 3);
+        assert!(result.is_ok());
+
+        let image = result.unwrap();
+        let decoder = png::Decoder::new(image.as_slice());
+        let reader = decoder.read_info().unwrap();
+        assert_eq!((6, 6), (reader.info().width, reader.info().height));
+    }
+
+    #[test]
+    fn test_format() {
+        assert_eq!(
+            Some(Format::Png),
+            Format::from_path(Path::new("out/day16.png"))
+        );
+        assert_eq!(Some(Format::Ascii), Format::from_path(Path::new("map.txt")));
+        assert_eq!(None, Format::from_path(Path::new("map")));
+    }
+
+    #[test]
+    fn test_palette() {
+        let colors: Vec<Color> = (0..20).map(palette).collect();
+        for (i, a) in colors.iter().enumerate() {
+            assert!(colors[i + 1..].iter().all(|b| a != b));
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use crate::{
+    error::with_file,
+    input::{normalize, read_input, read_input_from},
+};
+use anyhow::{bail, Result};
+use std::{fmt::Display, io::BufRead};
+
+/// The puzzle of a single day, the input is parsed once and shared by both parts.
+pub trait Solution {
+    type Input;
+
+    fn parse(data: &str) -> Result<Self::Input>;
+
+    /// Parses the puzzle input from a reader, e.g. stdin.
+    fn parse_reader(reader: impl BufRead) -> Result<Self::Input> {
+        Self::parse(&read_input_from(reader)?)
+    }
+
+    fn parse_file(filename: &str) -> Result<Self::Input> {
+        Self::parse(&read_input(filename)?).map_err(|err| with_file(err, filename))
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display>;
+
+    fn part2(input: &Self::Input) -> Result<impl Display>;
+}
+
+/// Parses the [`normalize`]d `data` and solves one part of the puzzle.
+pub fn solve<S: Solution>(part: u8, data: &str) -> Result<String> {
+    let input = S::parse(&normalize(data))?;
+    let answer = match part {
+        1 => S::part1(&input)?.to_string(),
+        2 => S::part2(&input)?.to_string(),
+        _ => bail!("There is no part {part}"),
+    };
+
+    Ok(answer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<u32>;
+
+        fn parse(data: &str) -> Result<Vec<u32>> {
+            Ok(data.lines().map(str::parse).collect::<Result<_, _>>()?)
+        }
+
+        fn part1(input: &Vec<u32>) -> Result<impl Display> {
+            Ok(input.iter().sum::<u32>())
+        }
+
+        fn part2(input: &Vec<u32>) -> Result<impl Display> {
+            Ok(input.iter().product::<u32>())
+        }
+    }
+
+    #[test]
+    fn test_solve() {
+        assert_eq!("9", solve::<Sum>(1, "2\n3\n4\n").unwrap());
+        assert_eq!("24", solve::<Sum>(2, "2\n3\n4\n").unwrap());
+        assert!(solve::<Sum>(3, "2\n3\n4\n").is_err());
+        assert!(solve::<Sum>(1, "x").is_err());
+        assert_eq!(
+            "9",
+            solve::<Sum>(1, "\u{feff}2\r\n3 \r\n4\r\n\r\n").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_reader() {
+        let result = Sum::parse_reader("2\n3\n4\n".as_bytes());
+        assert!(result.is_ok());
+        assert_eq!(vec![2, 3, 4], result.unwrap())
+    }
+}